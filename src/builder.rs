@@ -0,0 +1,212 @@
+use std::time::Duration;
+
+use anyhow::{bail, Context};
+use log::debug;
+use reqwest::{
+    header::{self, HeaderMap, HeaderName, HeaderValue},
+    Proxy,
+};
+
+use crate::{
+    access_token_store, rate_limit::RateLimiter, AccessTokenStore, RateLimit, RetryPolicy,
+    ScoopitAPI, ScoopitAPIClient,
+};
+
+const USER_AGENT: &str = "reqwest (scoopit-api-rs)";
+
+/// Builder of a `ScoopitAPIClient`, allowing to configure the HTTP settings of the client.
+///
+/// Use `ScoopitAPIClient::builder()` to get a builder with the default settings: 5s connect
+/// timeout, 60s timeout, `reqwest (scoopit-api-rs)` user agent and default `RetryPolicy`.
+#[derive(Debug)]
+pub struct ScoopitAPIClientBuilder {
+    scoopit_api: ScoopitAPI,
+    client: Option<reqwest::Client>,
+    connect_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+    app_name: Option<String>,
+    proxy: Option<Proxy>,
+    default_headers: HeaderMap,
+    retry_policy: RetryPolicy,
+    rate_limit: Option<RateLimit>,
+}
+
+impl Default for ScoopitAPIClientBuilder {
+    fn default() -> Self {
+        Self {
+            scoopit_api: ScoopitAPI::default(),
+            client: None,
+            connect_timeout: None,
+            timeout: None,
+            app_name: None,
+            proxy: None,
+            default_headers: HeaderMap::new(),
+            retry_policy: RetryPolicy::default(),
+            rate_limit: None,
+        }
+    }
+}
+
+impl ScoopitAPIClientBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The scoop.it API endpoints (`ScoopitAPI::default()` if not specified).
+    pub fn scoopit_api(self, scoopit_api: ScoopitAPI) -> Self {
+        Self {
+            scoopit_api,
+            ..self
+        }
+    }
+
+    /// Use an existing `reqwest::Client` to perform the requests, for example to share its
+    /// connection pool.
+    ///
+    /// The client is used as is, so it cannot be combined with the timeouts, app name, proxy and
+    /// default headers of the builder: `build()` fails if any of them is set.
+    pub fn http_client(self, client: reqwest::Client) -> Self {
+        Self {
+            client: Some(client),
+            ..self
+        }
+    }
+
+    /// Timeout of the connection to the server.
+    pub fn connect_timeout(self, connect_timeout: Duration) -> Self {
+        Self {
+            connect_timeout: Some(connect_timeout),
+            ..self
+        }
+    }
+
+    /// Total timeout of a request, from connection to the end of the response body.
+    pub fn timeout(self, timeout: Duration) -> Self {
+        Self {
+            timeout: Some(timeout),
+            ..self
+        }
+    }
+
+    /// Name of the application using the client, appended to the user agent.
+    pub fn app_name(self, app_name: impl Into<String>) -> Self {
+        Self {
+            app_name: Some(app_name.into()),
+            ..self
+        }
+    }
+
+    /// Send all the requests through the given proxy.
+    pub fn proxy(self, proxy: Proxy) -> Self {
+        Self {
+            proxy: Some(proxy),
+            ..self
+        }
+    }
+
+    /// Add a header sent with all the requests.
+    pub fn default_header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.default_headers.insert(name, value);
+        self
+    }
+
+    /// The retry policy of the client (`RetryPolicy::default()` if not specified).
+    pub fn retry_policy(self, retry_policy: RetryPolicy) -> Self {
+        Self {
+            retry_policy,
+            ..self
+        }
+    }
+
+    /// Limit the rate of the requests sent by the client, requests are not limited by default.
+    pub fn rate_limit(self, rate_limit: RateLimit) -> Self {
+        Self {
+            rate_limit: Some(rate_limit),
+            ..self
+        }
+    }
+
+    /// Build the client, authenticated by the access tokens of `access_token_store`.
+    pub fn build(self, access_token_store: AccessTokenStore) -> anyhow::Result<ScoopitAPIClient> {
+        let client = self.create_http_client()?;
+        Ok(self.build_with(client, access_token_store))
+    }
+
+    /// Build the client, authenticated using client credentials authentication.
+    ///
+    /// Access token is requested from scoop.it using the `client_credentials` grant type. If it
+    /// fails, an error is returned.
+    pub async fn authenticate_with_client_credentials(
+        self,
+        client_id: &str,
+        client_secret: &str,
+    ) -> anyhow::Result<ScoopitAPIClient> {
+        let client = self.create_http_client()?;
+
+        let access_token = access_token_store::authenticate_with_client_credentials(
+            &client,
+            &self.scoopit_api,
+            client_id,
+            client_secret,
+        )
+        .await?;
+
+        debug!("Creating client with access token: {:?}", access_token);
+
+        let access_token_store = AccessTokenStore::new(
+            access_token,
+            self.scoopit_api.clone(),
+            client.clone(),
+            client_id.to_string(),
+            client_secret.to_string(),
+        );
+        Ok(self.build_with(client, access_token_store))
+    }
+
+    fn build_with(
+        self,
+        client: reqwest::Client,
+        access_token_store: AccessTokenStore,
+    ) -> ScoopitAPIClient {
+        ScoopitAPIClient {
+            scoopit_api: self.scoopit_api,
+            client,
+            access_token: access_token_store,
+            retry_policy: self.retry_policy,
+            rate_limiter: self.rate_limit.map(RateLimiter::new),
+        }
+    }
+
+    fn create_http_client(&self) -> anyhow::Result<reqwest::Client> {
+        if let Some(client) = &self.client {
+            if self.connect_timeout.is_some()
+                || self.timeout.is_some()
+                || self.app_name.is_some()
+                || self.proxy.is_some()
+                || !self.default_headers.is_empty()
+            {
+                bail!("HTTP settings cannot be applied to an existing HTTP client");
+            }
+            return Ok(client.clone());
+        }
+        let user_agent = match &self.app_name {
+            Some(app_name) => format!("{USER_AGENT} {app_name}"),
+            None => USER_AGENT.to_string(),
+        };
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::USER_AGENT,
+            HeaderValue::from_str(&user_agent).context("Invalid user agent")?,
+        );
+        headers.extend(self.default_headers.clone());
+
+        let mut builder = reqwest::ClientBuilder::new()
+            .connect_timeout(self.connect_timeout.unwrap_or(Duration::from_secs(5)))
+            .timeout(self.timeout.unwrap_or(Duration::from_secs(60)))
+            .default_headers(headers);
+        if let Some(proxy) = &self.proxy {
+            builder = builder.proxy(proxy.clone());
+        }
+        Ok(builder.build()?)
+    }
+}
@@ -1,4 +1,7 @@
-use std::fmt::{Debug, Display};
+use std::{
+    fmt::{Debug, Display},
+    time::Duration,
+};
 
 #[derive(Debug)]
 pub struct Error {
@@ -20,6 +23,18 @@ impl Error {
             false
         }
     }
+    /// The server rejected the request because too many requests were sent (HTTP 429)
+    pub fn is_rate_limited(&self) -> bool {
+        matches!(self.inner, Inner::RateLimited { .. })
+    }
+    /// The delay the server asked to wait before sending another request, if rate limited
+    pub fn retry_after(&self) -> Option<Duration> {
+        if let Inner::RateLimited { retry_after } = self.inner {
+            retry_after
+        } else {
+            None
+        }
+    }
 }
 
 impl std::error::Error for Error {}
@@ -35,6 +50,9 @@ impl From<reqwest::Error> for Error {
         match e.status() {
             Some(status) if status.as_u16() == 404 => Inner::NotFound.into(),
             Some(status) if status.as_u16() == 403 => Inner::Forbidden.into(),
+            Some(status) if status.as_u16() == 429 => {
+                Inner::RateLimited { retry_after: None }.into()
+            }
             _ => Inner::from(e).into(),
         }
     }
@@ -63,6 +81,8 @@ pub(crate) enum Inner {
     NotFound,
     #[error("Access to requested resource is forbidden")]
     Forbidden,
+    #[error("Too many requests sent to the server")]
+    RateLimited { retry_after: Option<Duration> },
     #[error("An error occurred: {}", .0)]
     HttpClient(#[from] reqwest::Error),
     #[error("Unable to deserialize response: {}", .0)]
@@ -2,24 +2,28 @@
 //!
 //! The client uses `reqwest` with `rustls` to perform HTTP requests to www.scoop.it API.
 use anyhow::Context;
+use futures::{stream, Stream, TryStreamExt};
 use jsonwebtokens::raw::TokenSlices;
-use log::debug;
+use log::{debug, warn};
 use oauth::AccessTokenResponse;
 pub use requests::*;
-use reqwest::header::CONTENT_TYPE;
+use reqwest::header::{CONTENT_TYPE, RETRY_AFTER};
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::{convert::TryFrom, convert::TryInto, fmt::Debug, time::Duration};
 
-use reqwest::{header, RequestBuilder, Url};
+use reqwest::{header, RequestBuilder, Response, StatusCode, Url};
 
 // reexport crates
 pub use reqwest;
 pub use url;
 
 mod access_token_store;
+mod builder;
 mod oauth;
+mod rate_limit;
 pub mod requests;
+mod retry;
 pub mod types;
 // Note we are using a very hacked slimmed&vendored version of serde_qs to allow serializing Vec in form of
 // vec=foo&vec=bar&vec=baz instead of regular serde_qs vec[1]=foo&vec[2]=bar&vec[3]=baz
@@ -28,6 +32,10 @@ pub mod serde_qs;
 pub mod error;
 
 pub use access_token_store::AccessTokenStore;
+pub use builder::ScoopitAPIClientBuilder;
+pub use rate_limit::RateLimit;
+use rate_limit::RateLimiter;
+pub use retry::RetryPolicy;
 
 /// Scoop.it API endpoints.
 ///
@@ -63,10 +71,17 @@ impl ScoopitAPI {
 ///
 /// All requests done by the client are authenticated using an access token. The token
 /// is automatically renewed be needed.
+///
+/// Requests failing with a transient error are retried according to the client `RetryPolicy`.
+///
+/// Use `ScoopitAPIClient::builder()` to configure the client (HTTP settings, retry policy, rate
+/// limit).
 pub struct ScoopitAPIClient {
     scoopit_api: ScoopitAPI,
     client: reqwest::Client,
     access_token: AccessTokenStore,
+    retry_policy: RetryPolicy,
+    rate_limiter: Option<RateLimiter>,
 }
 
 impl ScoopitAPIClient {
@@ -79,73 +94,101 @@ impl ScoopitAPIClient {
         client_id: &str,
         client_secret: &str,
     ) -> anyhow::Result<Self> {
-        let client = ScoopitAPIClient::create_client()?;
-
-        let access_token = access_token_store::authenticate_with_client_credentials(
-            &client,
-            &scoopit_api,
-            client_id,
-            client_secret,
-        )
-        .await?;
-
-        debug!("Creating client with access token: {:?}", access_token);
-
-        Ok(Self {
-            access_token: AccessTokenStore::new(
-                access_token,
-                scoopit_api.clone(),
-                client.clone(),
-                client_id.to_string(),
-                client_secret.to_string(),
-            ),
-            scoopit_api,
-            client,
-        })
+        Self::builder()
+            .scoopit_api(scoopit_api)
+            .authenticate_with_client_credentials(client_id, client_secret)
+            .await
     }
 
     pub fn new(
         scoopit_api: ScoopitAPI,
         access_token_store: AccessTokenStore,
     ) -> anyhow::Result<Self> {
-        Ok(Self {
-            access_token: access_token_store,
-            client: ScoopitAPIClient::create_client()?,
-            scoopit_api,
-        })
+        Self::builder()
+            .scoopit_api(scoopit_api)
+            .build(access_token_store)
     }
 
-    fn create_client() -> anyhow::Result<reqwest::Client> {
-        Ok(reqwest::ClientBuilder::new()
-            .connect_timeout(Duration::from_secs(5))
-            .timeout(Duration::from_secs(60))
-            .default_headers({
-                let mut headers = header::HeaderMap::new();
-                headers.insert(
-                    header::USER_AGENT,
-                    header::HeaderValue::from_static("reqwest (scoopit-api-rs)"),
-                );
-                headers
-            })
-            .build()?)
+    /// A builder to configure the client.
+    pub fn builder() -> ScoopitAPIClientBuilder {
+        ScoopitAPIClientBuilder::new()
     }
 
     async fn do_request<T: DeserializeOwned>(
         &self,
-        request: RequestBuilder,
+        mut request: RequestBuilder,
+        retriable: bool,
     ) -> Result<T, error::Error> {
-        let json = request
-            .header(
-                header::AUTHORIZATION,
-                format!("Bearer {}", self.access_token.get_access_token().await?),
-            )
-            .send()
-            .await?
-            .error_for_status()?
-            .text()
-            .await?;
-        debug!("Received response {json}");
-        Ok(serde_json::from_str::<T>(&json)?)
+        let max_attempts = self.retry_policy.max_attempts;
+        let mut attempt = 1;
+        loop {
+            // keep a copy of the request to be able to send it again
+            let retry_request = if attempt < max_attempts {
+                request.try_clone()
+            } else {
+                None
+            };
+            if let Some(rate_limiter) = &self.rate_limiter {
+                rate_limiter.acquire().await;
+            }
+            let response = request
+                .header(
+                    header::AUTHORIZATION,
+                    format!("Bearer {}", self.access_token.get_access_token().await?),
+                )
+                .send()
+                .await;
+            match (
+                self.retry_delay(&response, retriable, attempt),
+                retry_request,
+            ) {
+                (Some(delay), Some(retry_request)) => {
+                    let cause = match &response {
+                        Ok(response) => format!("server responded {}", response.status()),
+                        Err(e) => e.to_string(),
+                    };
+                    warn!("Request failed (attempt {attempt}/{max_attempts}), retrying in {delay:?}: {cause}");
+                    tokio::time::sleep(delay).await;
+                    request = retry_request;
+                    attempt += 1;
+                }
+                _ => {
+                    let response = response?;
+                    if response.status() == StatusCode::TOO_MANY_REQUESTS {
+                        return Err(error::Inner::RateLimited {
+                            retry_after: retry_after(&response),
+                        }
+                        .into());
+                    }
+                    let json = response.error_for_status()?.text().await?;
+                    debug!("Received response {json}");
+                    return Ok(serde_json::from_str::<T>(&json)?);
+                }
+            }
+        }
+    }
+
+    /// The delay to wait before retrying the request, `None` if the request must not be retried.
+    fn retry_delay(
+        &self,
+        response: &reqwest::Result<Response>,
+        retriable: bool,
+        attempt: u32,
+    ) -> Option<Duration> {
+        match response {
+            // rate limited requests have not been processed, they can always be retried
+            Ok(response) if response.status() == StatusCode::TOO_MANY_REQUESTS => {
+                match retry_after(response) {
+                    Some(delay) if delay > self.retry_policy.max_delay => None,
+                    Some(delay) => Some(delay),
+                    None => Some(self.retry_policy.delay(attempt)),
+                }
+            }
+            response if retriable && self.retry_policy.is_transient_failure(response) => {
+                Some(self.retry_policy.delay(attempt))
+            }
+            _ => None,
+        }
     }
 
     /// Perform a `GET` request to scoop.it API.
@@ -165,14 +208,15 @@ impl ScoopitAPIClient {
         url.set_query(Some(
             &serde_qs::to_string(&request).context("Cannot build the url")?,
         ));
-        let response: R::Response = self.do_request(self.client.get(url)).await?;
+        let response: R::Response = self.do_request(self.client.get(url), true).await?;
 
-        response.try_into().map_err(error::Error::from)
+        request.output(response).map_err(error::Error::from)
     }
 
     /// Perform a request with a triggers an update (or an action) to scoop.it API.
     ///
-    /// The request must implements the `UpdateRequest` trait.
+    /// The request must implements the `UpdateRequest` trait. It is retried on transient
+    /// failures only if it is idempotent.
     pub async fn update<R>(&self, request: R) -> Result<R::Output, error::Error>
     where
         R: UpdateRequest + Debug,
@@ -189,11 +233,70 @@ impl ScoopitAPIClient {
                     .request(request.method(), url)
                     .header(CONTENT_TYPE, R::content_type())
                     .body(request.body()?),
+                request.is_idempotent(),
             )
             .await?;
 
         response.try_into().map_err(error::Error::from)
     }
+
+    /// Stream all the items of a paginated request, fetching them page by page.
+    ///
+    /// The stream starts at the page of the request and ends after the last page, or on the first
+    /// error.
+    pub fn paginate<'a, R>(
+        &'a self,
+        request: R,
+    ) -> impl Stream<Item = Result<R::Item, error::Error>> + 'a
+    where
+        R: PaginatedRequest + 'a,
+    {
+        stream::try_unfold(Some(request), move |request| async move {
+            match request {
+                Some(mut request) => {
+                    let output = self.get(request.clone()).await?;
+                    let (items, has_more) = request.split_page(output);
+                    let next_request = if has_more {
+                        request.next_page();
+                        Some(request)
+                    } else {
+                        None
+                    };
+                    Ok::<_, error::Error>(Some((
+                        stream::iter(items.into_iter().map(Ok)),
+                        next_request,
+                    )))
+                }
+                None => Ok(None),
+            }
+        })
+        .try_flatten()
+    }
+
+    /// Stream all the curated posts of a topic, fetching them page by page.
+    ///
+    /// The topic is looked up by the `id` or `url_name` of the request, `curated` is the page size
+    /// (30 if not specified) and `since`/`to` restrict the posts to a time window. The stream starts
+    /// at `page` (0 if not specified) and ends after the last page.
+    pub fn topic_posts(
+        &self,
+        request: GetTopicRequest,
+    ) -> impl Stream<Item = Result<types::Post, error::Error>> + '_ {
+        self.paginate(request)
+    }
+}
+
+/// Parse the `Retry-After` header of a response, only the delay in seconds form is supported.
+fn retry_after(response: &Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+        .map(Duration::from_secs)
 }
 
 /// Renewal data of an access token
@@ -281,6 +384,7 @@ mod tests {
         SearchRequestType, TestRequest,
     };
 
+    use futures::{StreamExt, TryStreamExt};
     use std::sync::Once;
 
     static INIT: Once = Once::new();
@@ -377,6 +481,23 @@ mod tests {
             .is_not_found());
     }
 
+    #[tokio::test]
+    async fn topic_posts() {
+        let client = get_client().await;
+        let posts = client
+            .topic_posts(GetTopicRequest {
+                url_name: Some("best-of-photojournalism".to_string()),
+                curated: Some(10),
+                ncomments: Some(0),
+                ..Default::default()
+            })
+            .take(25)
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        assert_eq!(25, posts.len());
+    }
+
     #[tokio::test]
     async fn get_test() {
         let response = get_client()
@@ -0,0 +1,135 @@
+use std::time::Duration;
+
+use tokio::{
+    sync::Mutex,
+    time::{sleep, Instant},
+};
+
+/// Client side rate limit of the requests sent to scoop.it API.
+///
+/// This is a token bucket: up to `burst` requests can be sent at once, then one request is
+/// allowed every `interval`.
+#[derive(Clone, Debug)]
+pub struct RateLimit {
+    /// maximum number of requests that can be sent at once (size of the bucket)
+    pub burst: u32,
+    /// interval at which a new request is allowed (a token is added to the bucket)
+    pub interval: Duration,
+}
+
+impl RateLimit {
+    /// Allow `requests` requests per second, with bursts of up to `requests` requests.
+    pub fn per_second(requests: u32) -> Self {
+        Self::new(requests, Duration::from_secs(1))
+    }
+
+    /// Allow `requests` requests per minute, with bursts of up to `requests` requests.
+    pub fn per_minute(requests: u32) -> Self {
+        Self::new(requests, Duration::from_secs(60))
+    }
+
+    fn new(requests: u32, period: Duration) -> Self {
+        let requests = requests.max(1);
+        Self {
+            burst: requests,
+            interval: period / requests,
+        }
+    }
+}
+
+struct Bucket {
+    tokens: u32,
+    last_refill: Instant,
+}
+
+pub(crate) struct RateLimiter {
+    rate_limit: RateLimit,
+    bucket: Mutex<Bucket>,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(rate_limit: RateLimit) -> Self {
+        // an empty bucket would never allow any request
+        let rate_limit = RateLimit {
+            burst: rate_limit.burst.max(1),
+            ..rate_limit
+        };
+        Self {
+            bucket: Mutex::new(Bucket {
+                tokens: rate_limit.burst,
+                last_refill: Instant::now(),
+            }),
+            rate_limit,
+        }
+    }
+
+    /// Wait until a request can be sent.
+    pub(crate) async fn acquire(&self) {
+        // the lock is held while waiting so requests are sent in order
+        let mut bucket = self.bucket.lock().await;
+        loop {
+            let elapsed = bucket.last_refill.elapsed();
+            let new_tokens = (elapsed.as_nanos() / self.rate_limit.interval.as_nanos().max(1))
+                .min(u128::from(u32::MAX)) as u32;
+            if new_tokens > 0 {
+                bucket.tokens = bucket
+                    .tokens
+                    .saturating_add(new_tokens)
+                    .min(self.rate_limit.burst);
+                bucket.last_refill += self.rate_limit.interval * new_tokens;
+            }
+            if bucket.tokens > 0 {
+                bucket.tokens -= 1;
+                return;
+            }
+            sleep(self.rate_limit.interval.saturating_sub(elapsed)).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tokio::time::Instant;
+
+    use super::{RateLimit, RateLimiter};
+
+    #[tokio::test(start_paused = true)]
+    async fn acquire() {
+        let rate_limiter = RateLimiter::new(RateLimit {
+            burst: 2,
+            interval: Duration::from_secs(1),
+        });
+        let start = Instant::now();
+        // the burst is allowed at once
+        rate_limiter.acquire().await;
+        rate_limiter.acquire().await;
+        assert_eq!(Duration::ZERO, start.elapsed());
+        // then one request per interval
+        rate_limiter.acquire().await;
+        assert_eq!(Duration::from_secs(1), start.elapsed());
+        rate_limiter.acquire().await;
+        assert_eq!(Duration::from_secs(2), start.elapsed());
+        // the bucket is refilled while idle, up to the burst
+        tokio::time::sleep(Duration::from_secs(10)).await;
+        let start = Instant::now();
+        rate_limiter.acquire().await;
+        rate_limiter.acquire().await;
+        assert_eq!(Duration::ZERO, start.elapsed());
+        rate_limiter.acquire().await;
+        assert_eq!(Duration::from_secs(1), start.elapsed());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn acquire_with_zero_burst() {
+        let rate_limiter = RateLimiter::new(RateLimit {
+            burst: 0,
+            interval: Duration::from_secs(1),
+        });
+        let start = Instant::now();
+        rate_limiter.acquire().await;
+        rate_limiter.acquire().await;
+        assert_eq!(Duration::from_secs(1), start.elapsed());
+    }
+}
@@ -7,13 +7,15 @@ use std::{
 
 use anyhow::anyhow;
 use reqwest::Method;
-use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize, Serializer};
 
 use crate::{
     serde_qs,
     types::{
-        Post, RecipientsList, SearchResults, Source, SourceTypeData, SuggestionEngine, Topic,
-        TopicGroup, User,
+        NewsletterSend, Notification, Post, PostCounters, RecipientsList, ResolvedObject,
+        SearchResult, SearchResults, Sharer, SharerTarget, Source, SourceTypeData,
+        SuggestedContent, SuggestionEngine, SuggestionEngineSavedSearch, SuggestionEngineType,
+        Topic, TopicGroup, TopicStats, User,
     },
 };
 
@@ -80,7 +82,7 @@ impl Default for GetProfileRequest {
 /// Documentation of each field comes from the page above. Default values documented are used only
 /// ff the field is not present (`None`), `Default` implementation for this struct may differ from
 /// Scoop.it defaults to avoid retrieving the world while only looking at the user profile.
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct GetTopicRequest {
     /// long required, unless 'urlName' is provided - the id of the topic to lookup
@@ -110,7 +112,7 @@ pub struct GetTopicRequest {
     /// boolean optional, default to false - if true, the response will include the scheduled posts
     pub show_scheduled: bool,
 }
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, Clone)]
 pub enum GetTopicOrder {
     #[serde(rename = "tag")]
     Tag,
@@ -152,6 +154,12 @@ pub trait GetRequest: Serialize + Debug {
     type Output;
 
     fn endpoint(&self) -> Cow<'static, str>;
+
+    /// Convert the response returned by the Scoop.it API to the output, by default using the
+    /// `TryInto` conversion of the response.
+    fn output(&self, response: Self::Response) -> anyhow::Result<Self::Output> {
+        response.try_into()
+    }
 }
 
 /// A request that does an update, by default the body is serialized as
@@ -179,6 +187,38 @@ pub trait UpdateRequest: Serialize + Debug {
     fn method(&self) -> Method {
         Method::POST
     }
+
+    /// Whether sending the request several times has the same effect as sending it once, false
+    /// by default. Idempotent requests are retried on transient failures.
+    fn is_idempotent(&self) -> bool {
+        false
+    }
+}
+
+/// A `GET` request whose results are returned page by page.
+///
+/// `ScoopitAPIClient::paginate` uses it to walk through all the pages of the request.
+pub trait PaginatedRequest: GetRequest + Clone {
+    /// The type of the items of a page
+    type Item;
+
+    /// Split the output of the current page into its items, the returned boolean tells whether
+    /// there are more pages after this one.
+    fn split_page(&self, output: Self::Output) -> (Vec<Self::Item>, bool);
+
+    /// Move the request to the next page.
+    fn next_page(&mut self);
+}
+
+/// Whether there are more pages after a page of `len` items, for requests returning `count` items
+/// per page (`default` if not specified). A short or empty page is the last one.
+fn has_more_by_count(len: usize, count: Option<u32>, default: u32) -> bool {
+    len > 0 && len >= count.unwrap_or(default) as usize
+}
+
+/// The page following `page`, the first page is 0.
+fn following_page(page: Option<u32>) -> Option<u32> {
+    Some(page.unwrap_or(0) + 1)
 }
 
 impl GetRequest for GetTopicRequest {
@@ -189,6 +229,25 @@ impl GetRequest for GetTopicRequest {
         "topic".into()
     }
 }
+
+impl PaginatedRequest for GetTopicRequest {
+    type Item = Post;
+
+    fn split_page(&self, output: Self::Output) -> (Vec<Self::Item>, bool) {
+        let page_size = self.curated.unwrap_or(30);
+        let fetched = (u64::from(self.page.unwrap_or(0)) + 1) * u64::from(page_size);
+        let posts = output.curated_posts.unwrap_or_default();
+        // an empty page ends the pagination whatever the page size (which may be zero)
+        let has_more = !posts.is_empty()
+            && posts.len() >= page_size as usize
+            && fetched < output.curated_post_count;
+        (posts, has_more)
+    }
+
+    fn next_page(&mut self) {
+        self.page = following_page(self.page);
+    }
+}
 impl GetRequest for GetProfileRequest {
     type Response = UserResponse;
     type Output = User;
@@ -198,101 +257,1310 @@ impl GetRequest for GetProfileRequest {
     }
 }
 
-#[derive(Deserialize)]
-pub struct TopicResponse {
-    pub topic: Option<Topic>,
-    pub error: Option<String>,
+/// Get a Post.
+///
+/// Maps parameters of https://www.scoop.it/dev/api/1/urls#post
+///
+/// Documentation of each field comes from the page above. Default values documented are used only
+/// ff the field is not present (`None`), `Default` implementation for this struct may differ from
+/// Scoop.it defaults to avoid retrieving the world while only looking at the post.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPostRequest {
+    /// long required - the id of the post to lookup
+    pub id: i64,
+    /// int optional, default to 100 - the maximum number of comments to retrieve for this post
+    pub ncomments: Option<u32>,
+    /// bool optional, default to true - returns or not the list of tags of the post
+    pub get_tags: Option<bool>,
+    /// bool optional, default to false - returns or not the tags of the topic of the post
+    pub get_tags_for_topic: Option<bool>,
+    /// bool optional, default to false - returns or not the stats of the topic of the post
+    pub get_stats_for_topic: Option<bool>,
 }
 
-#[derive(Deserialize)]
-pub struct UserResponse {
-    pub user: Option<User>,
+impl Default for GetPostRequest {
+    fn default() -> Self {
+        Self {
+            id: 0,
+            ncomments: Some(0), // force no comments
+            get_tags: Some(true),
+            get_tags_for_topic: None,
+            get_stats_for_topic: None,
+        }
+    }
+}
+
+impl GetRequest for GetPostRequest {
+    type Response = PostResponse;
+    type Output = Post;
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "post".into()
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct PostResponse {
+    pub post: Option<Post>,
     pub error: Option<String>,
 }
 
-impl TryFrom<UserResponse> for User {
+impl TryFrom<PostResponse> for Post {
     type Error = anyhow::Error;
 
-    fn try_from(value: UserResponse) -> Result<Self, Self::Error> {
+    fn try_from(value: PostResponse) -> Result<Self, Self::Error> {
         if let Some(error) = value.error {
             Err(anyhow::anyhow!("Server returned an error: {}", error))
         } else {
             value
-                .user
-                .ok_or(anyhow::anyhow!("No user nor error in response body!"))
+                .post
+                .ok_or(anyhow::anyhow!("No post nor error in response body!"))
         }
     }
 }
-impl TryFrom<TopicResponse> for Topic {
-    type Error = anyhow::Error;
 
-    fn try_from(value: TopicResponse) -> Result<Self, Self::Error> {
-        if let Some(error) = value.error {
-            Err(anyhow::anyhow!("Server returned an error: {}", error))
-        } else {
-            value
-                .topic
-                .ok_or(anyhow::anyhow!("No user no topic in response body!"))
-        }
+/// Form body of the requests of action based endpoints (`post`, `topic`): the parameters of the
+/// request along with the `action` to perform.
+#[derive(Serialize)]
+struct ActionBody<'a, T> {
+    action: &'static str,
+    #[serde(flatten)]
+    params: &'a T,
+}
+
+fn action_body<T: Serialize>(action: &'static str, params: &T) -> anyhow::Result<Vec<u8>> {
+    Ok(serde_qs::to_string(&ActionBody { action, params })?.into_bytes())
+}
+
+/// `shareOn` parameter is a json array of sharers
+fn share_on_as_json<S: Serializer>(
+    share_on: &[SharerTarget],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&serde_json::to_string(share_on).map_err(serde::ser::Error::custom)?)
+}
+
+fn optional_share_on_as_json<S: Serializer>(
+    share_on: &Option<Vec<SharerTarget>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match share_on {
+        Some(share_on) => share_on_as_json(share_on, serializer),
+        None => serializer.serialize_none(),
     }
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+/// Create a post in a topic.
+///
+/// Maps the `create` action of https://www.scoop.it/dev/api/1/urls#post
+#[derive(Serialize, Debug, Default)]
 #[serde(rename_all = "camelCase")]
-pub enum SearchRequestType {
-    User,
-    Topic,
-    Post,
+pub struct CreatePostRequest {
+    /// long mandatory - the id of the topic the post is created in
+    pub topic_id: u64,
+    /// string optional - the url of the curated content
+    pub url: Option<String>,
+    /// string optional - the title of the post, fetched from the url if not provided
+    pub title: Option<String>,
+    /// string optional - the content of the post, fetched from the url if not provided
+    pub content: Option<String>,
+    /// string optional - the insight of the curator
+    pub insight: Option<String>,
+    /// string[] optional - the tags of the post
+    #[serde(rename = "tag")]
+    pub tags: Option<Vec<String>>,
+    /// string optional - the url of the image of the post
+    pub image_url: Option<String>,
+    /// sharers optional - share the post on these sharers once published
+    #[serde(serialize_with = "optional_share_on_as_json")]
+    pub share_on: Option<Vec<SharerTarget>>,
+    /// timestamp optional - publication date of the post (in millis from unix epoch), the post is
+    /// scheduled if this date is in the future
+    pub publication_date: Option<i64>,
 }
 
-impl FromStr for SearchRequestType {
-    type Err = anyhow::Error;
+impl UpdateRequest for CreatePostRequest {
+    type Response = PostResponse;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "user" => Ok(SearchRequestType::User),
-            "topic" => Ok(SearchRequestType::Topic),
-            "post" => Ok(SearchRequestType::Post),
-            other => Err(anyhow::anyhow!("Invalid request type: {}", other)),
-        }
+    type Output = Post;
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "post".into()
+    }
+
+    fn body(&self) -> anyhow::Result<Vec<u8>> {
+        action_body("create", self)
     }
 }
 
-/// Perform a search.
+/// Edit a post.
 ///
-/// Maps parameters of https://www.scoop.it/dev/api/1/urls#search
+/// Maps the `edit` action of https://www.scoop.it/dev/api/1/urls#post
 ///
-/// Documentation of each field comes from the page above. Default values documented are used only
-/// ff the field is not present (`None`), `Default` implementation for this struct may differ from
-/// Scoop.it defaults to avoid retrieving the world while only looking at the user profile.
+/// Fields left to `None` are not modified.
+#[derive(Serialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct EditPostRequest {
+    /// long mandatory - the id of the post to edit
+    pub id: i64,
+    /// string optional - the new title of the post
+    pub title: Option<String>,
+    /// string optional - the new content of the post
+    pub content: Option<String>,
+    /// string optional - the new insight of the post
+    pub insight: Option<String>,
+    /// string[] optional - the new tags of the post
+    #[serde(rename = "tag")]
+    pub tags: Option<Vec<String>>,
+    /// string optional - the url of the new image of the post
+    pub image_url: Option<String>,
+}
+
+impl UpdateRequest for EditPostRequest {
+    type Response = PostResponse;
+
+    type Output = Post;
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "post".into()
+    }
+
+    fn body(&self) -> anyhow::Result<Vec<u8>> {
+        action_body("edit", self)
+    }
+}
+
+/// Delete a post.
+///
+/// Maps the `delete` action of https://www.scoop.it/dev/api/1/urls#post
 #[derive(Serialize, Debug)]
+pub struct DeletePostRequest {
+    /// long mandatory - the id of the post to delete
+    pub id: i64,
+}
+
+impl UpdateRequest for DeletePostRequest {
+    type Response = EmptyUpdateResponse;
+
+    type Output = ();
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "post".into()
+    }
+
+    fn body(&self) -> anyhow::Result<Vec<u8>> {
+        action_body("delete", self)
+    }
+}
+
+/// Accept a curable post (suggestion) in its topic.
+///
+/// Maps the `accept` action of https://www.scoop.it/dev/api/1/urls#post
+///
+/// Fields left to `None` keep the values of the suggestion.
+#[derive(Serialize, Debug, Default)]
 #[serde(rename_all = "camelCase")]
-pub struct SearchRequest {
-    ///string - type of object searched: "user", "topic" or "post"
-    #[serde(rename = "type")]
-    pub search_type: SearchRequestType,
-    /// string - the search query
-    pub query: String,
-    /// int optional, default to 50 - the number of item per page
+pub struct AcceptPostRequest {
+    /// long mandatory - the id of the curable post to accept
+    pub id: i64,
+    /// long optional - the id of the topic to accept the post in, defaults to the topic of the suggestion
+    pub topic_id: Option<u64>,
+    /// string optional - override the title of the post
+    pub title: Option<String>,
+    /// string optional - override the content of the post
+    pub content: Option<String>,
+    /// string optional - the insight of the curator
+    pub insight: Option<String>,
+    /// string[] optional - the tags of the post
+    #[serde(rename = "tag")]
+    pub tags: Option<Vec<String>>,
+    /// string optional - override the url of the image of the post
+    pub image_url: Option<String>,
+    /// sharers optional - share the post on these sharers once published
+    #[serde(serialize_with = "optional_share_on_as_json")]
+    pub share_on: Option<Vec<SharerTarget>>,
+    /// timestamp optional - publication date of the post (in millis from unix epoch), the post is
+    /// scheduled if this date is in the future
+    pub publication_date: Option<i64>,
+}
+
+impl UpdateRequest for AcceptPostRequest {
+    type Response = PostResponse;
+
+    type Output = Post;
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "post".into()
+    }
+
+    fn body(&self) -> anyhow::Result<Vec<u8>> {
+        action_body("accept", self)
+    }
+}
+
+/// Refuse a curable post (suggestion).
+///
+/// Maps the `refuse` action of https://www.scoop.it/dev/api/1/urls#post
+#[derive(Serialize, Debug)]
+pub struct RefusePostRequest {
+    /// long mandatory - the id of the curable post to refuse
+    pub id: i64,
+    /// string optional - the reason of the refusal, sent to the suggester if the post is a user suggestion
+    pub reason: Option<String>,
+}
+
+impl UpdateRequest for RefusePostRequest {
+    type Response = EmptyUpdateResponse;
+
+    type Output = ();
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "post".into()
+    }
+
+    fn body(&self) -> anyhow::Result<Vec<u8>> {
+        action_body("refuse", self)
+    }
+}
+
+/// Forward a curable post (suggestion) to another topic.
+///
+/// Maps the `forward` action of https://www.scoop.it/dev/api/1/urls#post
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ForwardPostRequest {
+    /// long mandatory - the id of the curable post to forward
+    pub id: i64,
+    /// long mandatory - the id of the topic the post is forwarded to
+    pub topic_id: u64,
+}
+
+impl UpdateRequest for ForwardPostRequest {
+    type Response = EmptyUpdateResponse;
+
+    type Output = ();
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "post".into()
+    }
+
+    fn body(&self) -> anyhow::Result<Vec<u8>> {
+        action_body("forward", self)
+    }
+}
+
+/// Rescoop a post into another topic.
+///
+/// Maps the `rescoop` action of https://www.scoop.it/dev/api/1/urls#post
+#[derive(Serialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct RescoopPostRequest {
+    /// long mandatory - the id of the post to rescoop
+    pub id: i64,
+    /// long mandatory - the id of the topic the post is rescooped into
+    pub dest_topic_id: u64,
+    /// string optional - the insight of the curator
+    pub insight: Option<String>,
+    /// string[] optional - the tags of the rescooped post
+    #[serde(rename = "tag")]
+    pub tags: Option<Vec<String>>,
+    /// sharers optional - share the rescooped post on these sharers
+    #[serde(serialize_with = "optional_share_on_as_json")]
+    pub share_on: Option<Vec<SharerTarget>>,
+}
+
+impl UpdateRequest for RescoopPostRequest {
+    type Response = PostResponse;
+
+    type Output = Post;
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "post".into()
+    }
+
+    fn body(&self) -> anyhow::Result<Vec<u8>> {
+        action_body("rescoop", self)
+    }
+}
+
+/// Pin a post on its topic, replacing the currently pinned post if any.
+///
+/// Maps the `pin` action of https://www.scoop.it/dev/api/1/urls#post
+#[derive(Serialize, Debug)]
+pub struct PinPostRequest {
+    /// long mandatory - the id of the post to pin
+    pub id: i64,
+}
+
+impl UpdateRequest for PinPostRequest {
+    type Response = EmptyUpdateResponse;
+
+    type Output = ();
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "post".into()
+    }
+
+    fn body(&self) -> anyhow::Result<Vec<u8>> {
+        action_body("pin", self)
+    }
+}
+
+/// Unpin the pinned post of a topic (see `Topic::pinned_post`).
+///
+/// Maps the `unpin` action of https://www.scoop.it/dev/api/1/urls#post
+#[derive(Serialize, Debug)]
+pub struct UnpinPostRequest {
+    /// long mandatory - the id of the pinned post
+    pub id: i64,
+}
+
+impl UpdateRequest for UnpinPostRequest {
+    type Response = EmptyUpdateResponse;
+
+    type Output = ();
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "post".into()
+    }
+
+    fn body(&self) -> anyhow::Result<Vec<u8>> {
+        action_body("unpin", self)
+    }
+}
+
+/// Change the publication date of a scheduled post.
+///
+/// Maps the `reschedule` action of https://www.scoop.it/dev/api/1/urls#post
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ReschedulePostRequest {
+    /// long mandatory - the id of the scheduled post
+    pub id: i64,
+    /// timestamp mandatory - the new publication date of the post (in millis from unix epoch)
+    pub publication_date: i64,
+}
+
+impl UpdateRequest for ReschedulePostRequest {
+    type Response = PostResponse;
+
+    type Output = Post;
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "post".into()
+    }
+
+    fn body(&self) -> anyhow::Result<Vec<u8>> {
+        action_body("reschedule", self)
+    }
+}
+
+/// Get the scheduled posts of a topic, the planned date of each post is its `publication_date`.
+///
+/// This is a `GetTopicRequest` with `show_scheduled` set, only the posts published after
+/// `scheduled_after` are returned. Scheduled posts come first in the topic, so pagination stops at
+/// the first page containing an already published post.
+#[derive(Debug, Clone)]
+pub struct GetScheduledPostsRequest {
+    /// the id of the topic to lookup, required unless `url_name` is provided
+    pub id: Option<u64>,
+    /// the urlName of the topic to lookup, required unless `id` is provided
+    pub url_name: Option<String>,
+    /// number of posts to retrieve (defaults to 30)
     pub count: Option<u32>,
-    /// int optional, default to 0 -the page number to return, the first page is 0
+    /// the page of posts to retrieve
     pub page: Option<u32>,
-    /// string optional, default to "en" - the language of the content to search into
-    pub lang: Option<String>,
-    /// long optional - the id of the topic to search posts into
-    pub topic_id: Option<u32>,
-    /// bool optional, default to true - returns or not list of tags for each returned topic / post. only for type="topic" or type="post"
-    pub get_tags: bool,
-    /// bool optional, default to true - returns or not creator of each returned topic. only for type="topic"
-    pub get_creator: bool,
-    /// bool optional, default to true - returns or not stats for each returned topic. only for type="topic"
-    pub get_stats: bool,
-    /// bool optional, default to true - returns or not tags for topic of each returned post. only for type="post"
-    pub get_tags_for_topic: bool,
-    /// bool optional, default to true - returns or not stats for topic of each returned post. only for type="post"
-    pub get_stats_for_topic: bool,
-    /// bool optional, default to false - also returns topics having no posts. only for type="topic"
-    pub include_empty_topics: bool,
+    /// only posts published after this date are scheduled, usually the current date (in millis
+    /// from unix epoch)
+    pub scheduled_after: i64,
+}
+
+impl GetScheduledPostsRequest {
+    /// Get the posts of the topic `id` scheduled after `now` (in millis from unix epoch).
+    pub fn with_id(id: u64, now: i64) -> Self {
+        Self {
+            id: Some(id),
+            url_name: None,
+            count: None,
+            page: None,
+            scheduled_after: now,
+        }
+    }
+
+    /// Get the posts of the topic `url_name` scheduled after `now` (in millis from unix epoch).
+    pub fn with_url_name(url_name: impl Into<String>, now: i64) -> Self {
+        Self {
+            id: None,
+            url_name: Some(url_name.into()),
+            count: None,
+            page: None,
+            scheduled_after: now,
+        }
+    }
+
+    fn topic_request(&self) -> GetTopicRequest {
+        GetTopicRequest {
+            id: self.id,
+            url_name: self.url_name.clone(),
+            curated: self.count.or(Some(30)),
+            page: self.page,
+            ncomments: Some(0),
+            show_scheduled: true,
+            ..Default::default()
+        }
+    }
+}
+
+impl Serialize for GetScheduledPostsRequest {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.topic_request().serialize(serializer)
+    }
+}
+
+impl GetRequest for GetScheduledPostsRequest {
+    type Response = ScheduledPostsResponse;
+    type Output = Vec<Post>;
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "topic".into()
+    }
+
+    fn output(&self, response: Self::Response) -> anyhow::Result<Self::Output> {
+        let posts: Vec<Post> = response.try_into()?;
+        Ok(posts
+            .into_iter()
+            .filter(|post| {
+                post.publication_date
+                    .is_some_and(|date| date > self.scheduled_after)
+            })
+            .collect())
+    }
+}
+
+impl PaginatedRequest for GetScheduledPostsRequest {
+    type Item = Post;
+
+    fn split_page(&self, output: Self::Output) -> (Vec<Self::Item>, bool) {
+        // already published posts have been filtered out of a page reaching them
+        let has_more = has_more_by_count(output.len(), self.count, 30);
+        (output, has_more)
+    }
+
+    fn next_page(&mut self) {
+        self.page = following_page(self.page);
+    }
+}
+
+/// The page of the topic, `GetScheduledPostsRequest` keeps only its scheduled posts.
+#[derive(Deserialize)]
+#[serde(transparent)]
+pub struct ScheduledPostsResponse(TopicResponse);
+
+impl TryFrom<ScheduledPostsResponse> for Vec<Post> {
+    type Error = anyhow::Error;
+
+    fn try_from(value: ScheduledPostsResponse) -> Result<Self, Self::Error> {
+        Ok(Topic::try_from(value.0)?.curated_posts.unwrap_or_default())
+    }
+}
+
+/// Add a comment on a post as the current user.
+///
+/// Maps the `comment` action of https://www.scoop.it/dev/api/1/urls#post
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentPostRequest {
+    /// long mandatory - the id of the post to comment
+    pub id: i64,
+    /// string mandatory - the text of the comment
+    pub comment_text: String,
+}
+
+impl UpdateRequest for CommentPostRequest {
+    type Response = PostResponse;
+
+    type Output = Post;
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "post".into()
+    }
+
+    fn body(&self) -> anyhow::Result<Vec<u8>> {
+        action_body("comment", self)
+    }
+}
+
+/// Delete a comment of a post.
+///
+/// Maps the `deleteComment` action of https://www.scoop.it/dev/api/1/urls#post
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DeletePostCommentRequest {
+    /// long mandatory - the id of the commented post
+    pub id: i64,
+    /// long mandatory - the id of the comment to delete
+    pub comment_id: i64,
+}
+
+impl UpdateRequest for DeletePostCommentRequest {
+    type Response = EmptyUpdateResponse;
+
+    type Output = ();
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "post".into()
+    }
+
+    fn body(&self) -> anyhow::Result<Vec<u8>> {
+        action_body("deleteComment", self)
+    }
+}
+
+impl TryFrom<PostResponse> for PostCounters {
+    type Error = anyhow::Error;
+
+    fn try_from(value: PostResponse) -> Result<Self, Self::Error> {
+        Ok(PostCounters::from(&Post::try_from(value)?))
+    }
+}
+
+/// Thank a post as the current user.
+///
+/// Maps the `thank` action of https://www.scoop.it/dev/api/1/urls#post
+#[derive(Serialize, Debug)]
+pub struct ThankPostRequest {
+    /// long mandatory - the id of the post to thank
+    pub id: i64,
+}
+
+impl UpdateRequest for ThankPostRequest {
+    type Response = PostResponse;
+
+    type Output = PostCounters;
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "post".into()
+    }
+
+    fn body(&self) -> anyhow::Result<Vec<u8>> {
+        action_body("thank", self)
+    }
+}
+
+/// Remove the thank of the current user on a post.
+///
+/// Maps the `unthank` action of https://www.scoop.it/dev/api/1/urls#post
+#[derive(Serialize, Debug)]
+pub struct UnthankPostRequest {
+    /// long mandatory - the id of the post to unthank
+    pub id: i64,
+}
+
+impl UpdateRequest for UnthankPostRequest {
+    type Response = PostResponse;
+
+    type Output = PostCounters;
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "post".into()
+    }
+
+    fn body(&self) -> anyhow::Result<Vec<u8>> {
+        action_body("unthank", self)
+    }
+}
+
+/// Add a reaction of the current user on a post.
+///
+/// Maps the `react` action of https://www.scoop.it/dev/api/1/urls#post
+#[derive(Serialize, Debug)]
+pub struct AddPostReactionRequest {
+    /// long mandatory - the id of the post to react to
+    pub id: i64,
+    /// string mandatory - the reaction, eg. "like", "love", "insightful"
+    pub reaction: String,
+}
+
+impl UpdateRequest for AddPostReactionRequest {
+    type Response = PostResponse;
+
+    type Output = PostCounters;
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "post".into()
+    }
+
+    fn body(&self) -> anyhow::Result<Vec<u8>> {
+        action_body("react", self)
+    }
+}
+
+/// Remove a reaction of the current user on a post.
+///
+/// Maps the `unreact` action of https://www.scoop.it/dev/api/1/urls#post
+#[derive(Serialize, Debug)]
+pub struct RemovePostReactionRequest {
+    /// long mandatory - the id of the post
+    pub id: i64,
+    /// string mandatory - the reaction to remove
+    pub reaction: String,
+}
+
+impl UpdateRequest for RemovePostReactionRequest {
+    type Response = PostResponse;
+
+    type Output = PostCounters;
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "post".into()
+    }
+
+    fn body(&self) -> anyhow::Result<Vec<u8>> {
+        action_body("unreact", self)
+    }
+}
+
+/// Share a post on some sharers of the current user.
+///
+/// Maps the `share` action of https://www.scoop.it/dev/api/1/urls#post
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SharePostRequest {
+    /// long mandatory - the id of the post to share
+    pub id: i64,
+    /// string optional - the message of the share, defaults to the title of the post
+    pub text: Option<String>,
+    /// sharers mandatory - the sharers to share the post on (see `GetSharersRequest`)
+    #[serde(serialize_with = "share_on_as_json")]
+    pub share_on: Vec<SharerTarget>,
+}
+
+impl UpdateRequest for SharePostRequest {
+    type Response = EmptyUpdateResponse;
+
+    type Output = ();
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "post".into()
+    }
+
+    fn body(&self) -> anyhow::Result<Vec<u8>> {
+        action_body("share", self)
+    }
+}
+
+/// Get the sharers (connected social accounts) of the current user.
+///
+/// https://www.scoop.it/dev/api/1/urls#sharers
+#[derive(Serialize, Debug, Default)]
+pub struct GetSharersRequest {
+    _dummy: (),
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+pub enum GetSharersResponse {
+    Ok { sharers: Vec<Sharer> },
+    Err { error: String },
+}
+
+impl GetRequest for GetSharersRequest {
+    type Response = GetSharersResponse;
+    type Output = Vec<Sharer>;
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "sharers".into()
+    }
+}
+
+impl TryFrom<GetSharersResponse> for Vec<Sharer> {
+    type Error = anyhow::Error;
+
+    fn try_from(value: GetSharersResponse) -> Result<Self, Self::Error> {
+        match value {
+            GetSharersResponse::Ok { sharers } => Ok(sharers),
+            GetSharersResponse::Err { error } => Err(anyhow!("Server returned an error: {error}")),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct TopicResponse {
+    pub topic: Option<Topic>,
+    pub error: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct UserResponse {
+    pub user: Option<User>,
+    pub error: Option<String>,
+}
+
+impl TryFrom<UserResponse> for User {
+    type Error = anyhow::Error;
+
+    fn try_from(value: UserResponse) -> Result<Self, Self::Error> {
+        if let Some(error) = value.error {
+            Err(anyhow::anyhow!("Server returned an error: {}", error))
+        } else {
+            value
+                .user
+                .ok_or(anyhow::anyhow!("No user nor error in response body!"))
+        }
+    }
+}
+impl TryFrom<TopicResponse> for Topic {
+    type Error = anyhow::Error;
+
+    fn try_from(value: TopicResponse) -> Result<Self, Self::Error> {
+        if let Some(error) = value.error {
+            Err(anyhow::anyhow!("Server returned an error: {}", error))
+        } else {
+            value
+                .topic
+                .ok_or(anyhow::anyhow!("No user no topic in response body!"))
+        }
+    }
+}
+
+/// Create a topic curated by the current user.
+///
+/// Maps the `create` action of https://www.scoop.it/dev/api/1/urls#topic
+#[derive(Serialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTopicRequest {
+    /// string mandatory - the name of the topic
+    pub name: String,
+    /// string optional - the url name of the topic, generated from the name if not provided
+    pub short_name: Option<String>,
+    /// string optional - the description of the topic
+    pub description: Option<String>,
+    /// string optional, default to "en" - the language of the topic
+    pub lang: Option<String>,
+    /// bool optional, default to false - whether the topic is private
+    pub is_private: Option<bool>,
+    /// string optional - the url of the image of the topic
+    pub image_url: Option<String>,
+}
+
+impl UpdateRequest for CreateTopicRequest {
+    type Response = TopicResponse;
+
+    type Output = Topic;
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "topic".into()
+    }
+
+    fn body(&self) -> anyhow::Result<Vec<u8>> {
+        action_body("create", self)
+    }
+}
+
+/// Edit the metadata of a topic.
+///
+/// Maps the `edit` action of https://www.scoop.it/dev/api/1/urls#topic
+///
+/// Fields left to `None` are not modified.
+#[derive(Serialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct EditTopicRequest {
+    /// long mandatory - the id of the topic to edit
+    pub id: u64,
+    /// string optional - the new name of the topic
+    pub name: Option<String>,
+    /// string optional - the new description of the topic
+    pub description: Option<String>,
+    /// string optional - the new language of the topic
+    pub lang: Option<String>,
+    /// bool optional - whether the topic is private
+    pub is_private: Option<bool>,
+    /// string optional - the url of the new image of the topic
+    pub image_url: Option<String>,
+}
+
+impl UpdateRequest for EditTopicRequest {
+    type Response = TopicResponse;
+
+    type Output = Topic;
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "topic".into()
+    }
+
+    fn body(&self) -> anyhow::Result<Vec<u8>> {
+        action_body("edit", self)
+    }
+}
+
+/// Delete a topic and all its posts.
+///
+/// Maps the `delete` action of https://www.scoop.it/dev/api/1/urls#topic
+#[derive(Serialize, Debug)]
+pub struct DeleteTopicRequest {
+    /// long mandatory - the id of the topic to delete
+    pub id: u64,
+}
+
+impl UpdateRequest for DeleteTopicRequest {
+    type Response = EmptyUpdateResponse;
+
+    type Output = ();
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "topic".into()
+    }
+
+    fn body(&self) -> anyhow::Result<Vec<u8>> {
+        action_body("delete", self)
+    }
+}
+
+/// Follow a topic as the current user.
+///
+/// Maps the `follow` action of https://www.scoop.it/dev/api/1/urls#topic
+#[derive(Serialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct FollowTopicRequest {
+    /// long required, unless 'urlName' is provided - the id of the topic to follow
+    pub id: Option<u64>,
+    /// string required, unless 'id' is provided - the urlName of the topic to follow
+    pub url_name: Option<String>,
+}
+
+impl UpdateRequest for FollowTopicRequest {
+    type Response = EmptyUpdateResponse;
+
+    type Output = ();
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "topic".into()
+    }
+
+    fn body(&self) -> anyhow::Result<Vec<u8>> {
+        action_body("follow", self)
+    }
+}
+
+/// Unfollow a topic as the current user.
+///
+/// Maps the `unfollow` action of https://www.scoop.it/dev/api/1/urls#topic
+#[derive(Serialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct UnfollowTopicRequest {
+    /// long required, unless 'urlName' is provided - the id of the topic to unfollow
+    pub id: Option<u64>,
+    /// string required, unless 'id' is provided - the urlName of the topic to unfollow
+    pub url_name: Option<String>,
+}
+
+impl UpdateRequest for UnfollowTopicRequest {
+    type Response = EmptyUpdateResponse;
+
+    type Output = ();
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "topic".into()
+    }
+
+    fn body(&self) -> anyhow::Result<Vec<u8>> {
+        action_body("unfollow", self)
+    }
+}
+
+/// Rename a tag on all the posts of a topic.
+///
+/// Maps the `renameTag` action of https://www.scoop.it/dev/api/1/urls#topic
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RenameTopicTagRequest {
+    /// long mandatory - the id of the topic
+    pub id: u64,
+    /// string mandatory - the tag to rename
+    pub tag: String,
+    /// string mandatory - the new name of the tag
+    pub new_tag: String,
+}
+
+impl UpdateRequest for RenameTopicTagRequest {
+    type Response = EmptyUpdateResponse;
+
+    type Output = ();
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "topic".into()
+    }
+
+    fn body(&self) -> anyhow::Result<Vec<u8>> {
+        action_body("renameTag", self)
+    }
+}
+
+/// Merge several tags of a topic into a single one: posts tagged with any of `tags` get tagged
+/// with `into` instead.
+///
+/// Maps the `mergeTags` action of https://www.scoop.it/dev/api/1/urls#topic
+#[derive(Serialize, Debug)]
+pub struct MergeTopicTagsRequest {
+    /// long mandatory - the id of the topic
+    pub id: u64,
+    /// string[] mandatory - the tags to merge
+    #[serde(rename = "tag")]
+    pub tags: Vec<String>,
+    /// string mandatory - the tag the tags are merged into
+    pub into: String,
+}
+
+impl UpdateRequest for MergeTopicTagsRequest {
+    type Response = EmptyUpdateResponse;
+
+    type Output = ();
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "topic".into()
+    }
+
+    fn body(&self) -> anyhow::Result<Vec<u8>> {
+        action_body("mergeTags", self)
+    }
+}
+
+/// Remove a tag from all the posts of a topic.
+///
+/// Maps the `deleteTag` action of https://www.scoop.it/dev/api/1/urls#topic
+#[derive(Serialize, Debug)]
+pub struct DeleteTopicTagRequest {
+    /// long mandatory - the id of the topic
+    pub id: u64,
+    /// string mandatory - the tag to delete
+    pub tag: String,
+}
+
+impl UpdateRequest for DeleteTopicTagRequest {
+    type Response = EmptyUpdateResponse;
+
+    type Output = ();
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "topic".into()
+    }
+
+    fn body(&self) -> anyhow::Result<Vec<u8>> {
+        action_body("deleteTag", self)
+    }
+}
+
+/// Get the statistics of a topic, day by day, over a time range.
+///
+/// https://www.scoop.it/dev/api/1/urls#topic-stats
+#[derive(Serialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTopicStatsRequest {
+    /// long mandatory - the id of the topic
+    pub id: u64,
+    /// timestamp optional, default to 30 days ago - start of the range (in millis from unix epoch)
+    pub from: Option<i64>,
+    /// timestamp optional, default to now - end of the range (in millis from unix epoch)
+    pub to: Option<i64>,
+    /// bool optional, default to false - also returns the stats of each post of the topic
+    pub get_post_stats: bool,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+pub enum GetTopicStatsResponse {
+    Ok { stats: TopicStats },
+    Err { error: String },
+}
+
+impl GetRequest for GetTopicStatsRequest {
+    type Response = GetTopicStatsResponse;
+    type Output = TopicStats;
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "topic-stats".into()
+    }
+}
+
+impl TryFrom<GetTopicStatsResponse> for TopicStats {
+    type Error = anyhow::Error;
+
+    fn try_from(value: GetTopicStatsResponse) -> Result<Self, Self::Error> {
+        match value {
+            GetTopicStatsResponse::Ok { stats } => Ok(stats),
+            GetTopicStatsResponse::Err { error } => {
+                Err(anyhow!("Server returned an error: {error}"))
+            }
+        }
+    }
+}
+
+/// Get the notifications of the current user.
+///
+/// https://www.scoop.it/dev/api/1/urls#notifications
+#[derive(Serialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetNotificationsRequest {
+    /// timestamp optional - only retrieve notifications newer than this timestamp (in millis from unix epoch)
+    pub since: Option<i64>,
+    /// int optional, default to 50 - the maximum number of notifications to retrieve
+    pub count: Option<u32>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+pub enum GetNotificationsResponse {
+    Ok { notifications: Vec<Notification> },
+    Err { error: String },
+}
+
+impl GetRequest for GetNotificationsRequest {
+    type Response = GetNotificationsResponse;
+    type Output = Vec<Notification>;
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "notifications".into()
+    }
+}
+
+impl TryFrom<GetNotificationsResponse> for Vec<Notification> {
+    type Error = anyhow::Error;
+
+    fn try_from(value: GetNotificationsResponse) -> Result<Self, Self::Error> {
+        match value {
+            GetNotificationsResponse::Ok { notifications } => Ok(notifications),
+            GetNotificationsResponse::Err { error } => {
+                Err(anyhow!("Server returned an error: {error}"))
+            }
+        }
+    }
+}
+
+/// Get the followers of a topic.
+///
+/// https://www.scoop.it/dev/api/1/urls#topic-followers
+#[derive(Serialize, Debug, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTopicFollowersRequest {
+    /// long required, unless 'urlName' is provided - the id of the topic
+    pub id: Option<u64>,
+    /// string required, unless 'id' is provided - the urlName of the topic
+    pub url_name: Option<String>,
+    /// int optional, default to 50 - the number of followers per page
+    pub count: Option<u32>,
+    /// int optional, default to 0 - the page number to return, the first page is 0
+    pub page: Option<u32>,
+}
+
+impl GetRequest for GetTopicFollowersRequest {
+    type Response = UserListResponse;
+    type Output = Vec<User>;
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "topic/followers".into()
+    }
+}
+
+impl PaginatedRequest for GetTopicFollowersRequest {
+    type Item = User;
+
+    fn split_page(&self, output: Self::Output) -> (Vec<Self::Item>, bool) {
+        let has_more = has_more_by_count(output.len(), self.count, 50);
+        (output, has_more)
+    }
+
+    fn next_page(&mut self) {
+        self.page = following_page(self.page);
+    }
+}
+
+/// Get the followers of a user.
+///
+/// https://www.scoop.it/dev/api/1/urls#profile-followers
+#[derive(Serialize, Debug, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetUserFollowersRequest {
+    /// long optional - the id of the user - defaults to the current user
+    pub id: Option<u64>,
+    /// string optional - the shortName of the user - defaults to the current user
+    pub short_name: Option<String>,
+    /// int optional, default to 50 - the number of followers per page
+    pub count: Option<u32>,
+    /// int optional, default to 0 - the page number to return, the first page is 0
+    pub page: Option<u32>,
+}
+
+impl GetRequest for GetUserFollowersRequest {
+    type Response = UserListResponse;
+    type Output = Vec<User>;
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "profile/followers".into()
+    }
+}
+
+impl PaginatedRequest for GetUserFollowersRequest {
+    type Item = User;
+
+    fn split_page(&self, output: Self::Output) -> (Vec<Self::Item>, bool) {
+        let has_more = has_more_by_count(output.len(), self.count, 50);
+        (output, has_more)
+    }
+
+    fn next_page(&mut self) {
+        self.page = following_page(self.page);
+    }
+}
+
+/// Get the users followed by a user.
+///
+/// https://www.scoop.it/dev/api/1/urls#profile-followings
+#[derive(Serialize, Debug, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetUserFollowingsRequest {
+    /// long optional - the id of the user - defaults to the current user
+    pub id: Option<u64>,
+    /// string optional - the shortName of the user - defaults to the current user
+    pub short_name: Option<String>,
+    /// int optional, default to 50 - the number of followed users per page
+    pub count: Option<u32>,
+    /// int optional, default to 0 - the page number to return, the first page is 0
+    pub page: Option<u32>,
+}
+
+impl GetRequest for GetUserFollowingsRequest {
+    type Response = UserListResponse;
+    type Output = Vec<User>;
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "profile/followings".into()
+    }
+}
+
+impl PaginatedRequest for GetUserFollowingsRequest {
+    type Item = User;
+
+    fn split_page(&self, output: Self::Output) -> (Vec<Self::Item>, bool) {
+        let has_more = has_more_by_count(output.len(), self.count, 50);
+        (output, has_more)
+    }
+
+    fn next_page(&mut self) {
+        self.page = following_page(self.page);
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+pub enum UserListResponse {
+    Ok { users: Vec<User> },
+    Err { error: String },
+}
+
+impl TryFrom<UserListResponse> for Vec<User> {
+    type Error = anyhow::Error;
+
+    fn try_from(value: UserListResponse) -> Result<Self, Self::Error> {
+        match value {
+            UserListResponse::Ok { users } => Ok(users),
+            UserListResponse::Err { error } => Err(anyhow!("Server returned an error: {error}")),
+        }
+    }
+}
+
+/// Resolve a scoop.it url (topic, post, short url or profile url) to the object it points to.
+///
+/// https://www.scoop.it/dev/api/1/urls#resolver
+#[derive(Serialize, Debug)]
+pub struct ResolveUrlRequest {
+    /// string mandatory - the url to resolve
+    pub url: String,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+pub enum ResolveUrlResponse {
+    Ok(ResolvedObject),
+    Err { error: String },
+}
+
+impl GetRequest for ResolveUrlRequest {
+    type Response = ResolveUrlResponse;
+    type Output = ResolvedObject;
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "resolver".into()
+    }
+}
+
+impl TryFrom<ResolveUrlResponse> for ResolvedObject {
+    type Error = anyhow::Error;
+
+    fn try_from(value: ResolveUrlResponse) -> Result<Self, Self::Error> {
+        match value {
+            ResolveUrlResponse::Ok(object) => Ok(object),
+            ResolveUrlResponse::Err { error } => Err(anyhow!("Server returned an error: {error}")),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub enum SearchRequestType {
+    User,
+    Topic,
+    Post,
+}
+
+impl FromStr for SearchRequestType {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "user" => Ok(SearchRequestType::User),
+            "topic" => Ok(SearchRequestType::Topic),
+            "post" => Ok(SearchRequestType::Post),
+            other => Err(anyhow::anyhow!("Invalid request type: {}", other)),
+        }
+    }
+}
+
+/// Perform a search.
+///
+/// Maps parameters of https://www.scoop.it/dev/api/1/urls#search
+///
+/// Documentation of each field comes from the page above. Default values documented are used only
+/// ff the field is not present (`None`), `Default` implementation for this struct may differ from
+/// Scoop.it defaults to avoid retrieving the world while only looking at the user profile.
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchRequest {
+    ///string - type of object searched: "user", "topic" or "post"
+    #[serde(rename = "type")]
+    pub search_type: SearchRequestType,
+    /// string - the search query
+    pub query: String,
+    /// int optional, default to 50 - the number of item per page
+    pub count: Option<u32>,
+    /// int optional, default to 0 -the page number to return, the first page is 0
+    pub page: Option<u32>,
+    /// string optional, default to "en" - the language of the content to search into
+    pub lang: Option<String>,
+    /// long optional - the id of the topic to search posts into
+    pub topic_id: Option<u32>,
+    /// bool optional, default to true - returns or not list of tags for each returned topic / post. only for type="topic" or type="post"
+    pub get_tags: bool,
+    /// bool optional, default to true - returns or not creator of each returned topic. only for type="topic"
+    pub get_creator: bool,
+    /// bool optional, default to true - returns or not stats for each returned topic. only for type="topic"
+    pub get_stats: bool,
+    /// bool optional, default to true - returns or not tags for topic of each returned post. only for type="post"
+    pub get_tags_for_topic: bool,
+    /// bool optional, default to true - returns or not stats for topic of each returned post. only for type="post"
+    pub get_stats_for_topic: bool,
+    /// bool optional, default to false - also returns topics having no posts. only for type="topic"
+    pub include_empty_topics: bool,
 }
 impl Default for SearchRequest {
     fn default() -> Self {
@@ -312,71 +1580,266 @@ impl Default for SearchRequest {
         }
     }
 }
-#[derive(Deserialize, Debug)]
-#[serde(rename_all = "camelCase")]
-pub struct SearchResponse {
-    pub users: Option<Vec<User>>,
-    pub topics: Option<Vec<Topic>>,
-    pub posts: Option<Vec<Post>>,
-    pub total_found: i32,
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchResponse {
+    pub users: Option<Vec<User>>,
+    pub topics: Option<Vec<Topic>>,
+    pub posts: Option<Vec<Post>>,
+    pub total_found: i32,
+}
+
+impl GetRequest for SearchRequest {
+    type Response = SearchResponse;
+
+    type Output = SearchResults;
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "search".into()
+    }
+}
+
+impl PaginatedRequest for SearchRequest {
+    type Item = SearchResult;
+
+    fn split_page(&self, output: Self::Output) -> (Vec<Self::Item>, bool) {
+        let fetched = (u64::from(self.page.unwrap_or(0)) + 1) * u64::from(self.count.unwrap_or(50));
+        let has_more = i128::from(fetched) < i128::from(output.total_found);
+        let SearchResults {
+            users,
+            topics,
+            posts,
+            total_found: _,
+        } = output;
+        let items = users
+            .unwrap_or_default()
+            .into_iter()
+            .map(|user| SearchResult::User(Box::new(user)))
+            .chain(
+                topics
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|topic| SearchResult::Topic(Box::new(topic))),
+            )
+            .chain(
+                posts
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|post| SearchResult::Post(Box::new(post))),
+            )
+            .collect::<Vec<_>>();
+        let has_more = has_more && !items.is_empty();
+        (items, has_more)
+    }
+
+    fn next_page(&mut self) {
+        self.page = following_page(self.page);
+    }
+}
+
+impl TryFrom<SearchResponse> for SearchResults {
+    type Error = anyhow::Error;
+
+    fn try_from(value: SearchResponse) -> Result<Self, Self::Error> {
+        let SearchResponse {
+            users,
+            topics,
+            posts,
+            total_found,
+        } = value;
+        Ok(SearchResults {
+            users,
+            topics,
+            posts,
+            total_found,
+        })
+    }
+}
+
+/// Get the list of recipients lists
+///
+/// See https://www.scoop.it/dev/api/1/urls#recipients-list
+///
+#[derive(Serialize, Debug, Default)]
+pub struct GetRecipientsListRequest {
+    _dummy: (),
+}
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GetRecipientsListResponse {
+    list: Vec<RecipientsList>,
+}
+
+impl GetRequest for GetRecipientsListRequest {
+    type Response = GetRecipientsListResponse;
+    type Output = Vec<RecipientsList>;
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "recipients-list".into()
+    }
+}
+impl TryFrom<GetRecipientsListResponse> for Vec<RecipientsList> {
+    type Error = anyhow::Error;
+
+    fn try_from(value: GetRecipientsListResponse) -> Result<Self, Self::Error> {
+        Ok(value.list)
+    }
+}
+
+/// Create a recipients list
+///
+/// https://www.scoop.it/dev/api/1/urls#recipients-list
+#[derive(Serialize, Debug)]
+pub struct CreateRecipientsListRequest {
+    pub name: String,
+    #[serde(rename = "email")]
+    pub emails: Vec<String>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+pub enum RecipientsListResponse {
+    #[serde(rename_all = "camelCase")]
+    Ok {
+        recipients_list: RecipientsList,
+    },
+    Err {
+        error: String,
+    },
+}
+
+impl UpdateRequest for CreateRecipientsListRequest {
+    type Response = RecipientsListResponse;
+
+    type Output = RecipientsList;
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "recipients-list".into()
+    }
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+}
+
+impl TryFrom<RecipientsListResponse> for RecipientsList {
+    type Error = anyhow::Error;
+
+    fn try_from(value: RecipientsListResponse) -> Result<Self, Self::Error> {
+        match value {
+            RecipientsListResponse::Ok { recipients_list } => Ok(recipients_list),
+            RecipientsListResponse::Err { error } => {
+                Err(anyhow!("Server returned an error: {error}"))
+            }
+        }
+    }
+}
+
+/// Update a recipients list: rename it and/or add and remove emails.
+///
+/// https://www.scoop.it/dev/api/1/urls#recipients-list_id
+#[derive(Serialize, Debug, Default)]
+pub struct UpdateRecipientsListRequest {
+    #[serde(skip)]
+    pub recipients_list_id: i64,
+    pub name: Option<String>,
+    #[serde(rename = "addEmail")]
+    pub add_emails: Option<Vec<String>>,
+    #[serde(rename = "removeEmail")]
+    pub remove_emails: Option<Vec<String>>,
 }
 
-impl GetRequest for SearchRequest {
-    type Response = SearchResponse;
+impl UpdateRequest for UpdateRecipientsListRequest {
+    type Response = RecipientsListResponse;
 
-    type Output = SearchResults;
+    type Output = RecipientsList;
 
     fn endpoint(&self) -> Cow<'static, str> {
-        "search".into()
+        format!("recipients-list/{}", self.recipients_list_id).into()
     }
 }
 
-impl TryFrom<SearchResponse> for SearchResults {
-    type Error = anyhow::Error;
+/// Delete a recipients list
+///
+/// https://www.scoop.it/dev/api/1/urls#recipients-list_id
+#[derive(Serialize, Debug)]
+pub struct DeleteRecipientsListRequest {
+    #[serde(skip)]
+    pub recipients_list_id: i64,
+}
 
-    fn try_from(value: SearchResponse) -> Result<Self, Self::Error> {
-        let SearchResponse {
-            users,
-            topics,
-            posts,
-            total_found,
-        } = value;
-        Ok(SearchResults {
-            users,
-            topics,
-            posts,
-            total_found,
-        })
+impl UpdateRequest for DeleteRecipientsListRequest {
+    type Response = EmptyUpdateResponse;
+
+    type Output = ();
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("recipients-list/{}", self.recipients_list_id).into()
+    }
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn is_idempotent(&self) -> bool {
+        true
     }
 }
 
-/// Get the list of recipients lists
+/// Send a newsletter made of posts to a recipients list and/or to some emails.
 ///
-/// See https://www.scoop.it/dev/api/1/urls#recipients-list
+/// Posts of the newsletter are either the given `post_ids` or the `latest` posts of the topic
+/// `topic_id`.
 ///
+/// https://www.scoop.it/dev/api/1/urls#newsletter
 #[derive(Serialize, Debug, Default)]
-pub struct GetRecipientsListRequest {
-    _dummy: (),
+#[serde(rename_all = "camelCase")]
+pub struct SendNewsletterRequest {
+    /// string mandatory - the subject of the newsletter
+    pub subject: String,
+    /// string optional - the text introducing the posts of the newsletter
+    pub intro: Option<String>,
+    /// long[] required, unless 'topicId' is provided - the ids of the posts to send
+    #[serde(rename = "postId")]
+    pub post_ids: Option<Vec<i64>>,
+    /// long required, unless 'postId' is provided - the topic to send the latest posts of
+    pub topic_id: Option<u64>,
+    /// int optional, default to 10 - the number of latest posts of the topic to send
+    pub latest: Option<u32>,
+    /// long optional - the id of the recipients list to send the newsletter to
+    pub recipients_list_id: Option<i64>,
+    /// string[] optional - additional emails to send the newsletter to
+    #[serde(rename = "email")]
+    pub emails: Option<Vec<String>>,
 }
+
 #[derive(Deserialize, Debug)]
-#[serde(rename_all = "camelCase")]
-pub struct GetRecipientsListResponse {
-    list: Vec<RecipientsList>,
+#[serde(untagged)]
+pub enum SendNewsletterResponse {
+    Ok { newsletter: NewsletterSend },
+    Err { error: String },
 }
 
-impl GetRequest for GetRecipientsListRequest {
-    type Response = GetRecipientsListResponse;
-    type Output = Vec<RecipientsList>;
+impl UpdateRequest for SendNewsletterRequest {
+    type Response = SendNewsletterResponse;
+
+    type Output = NewsletterSend;
 
     fn endpoint(&self) -> Cow<'static, str> {
-        "recipients-list".into()
+        "newsletter".into()
     }
 }
-impl TryFrom<GetRecipientsListResponse> for Vec<RecipientsList> {
+
+impl TryFrom<SendNewsletterResponse> for NewsletterSend {
     type Error = anyhow::Error;
 
-    fn try_from(value: GetRecipientsListResponse) -> Result<Self, Self::Error> {
-        Ok(value.list)
+    fn try_from(value: SendNewsletterResponse) -> Result<Self, Self::Error> {
+        match value {
+            SendNewsletterResponse::Ok { newsletter } => Ok(newsletter),
+            SendNewsletterResponse::Err { error } => {
+                Err(anyhow!("Server returned an error: {error}"))
+            }
+        }
     }
 }
 
@@ -504,6 +1967,205 @@ impl TryFrom<GetSuggestionEnginesResponse> for Vec<SuggestionEngine> {
     }
 }
 
+/// Create a suggestion engine
+///
+/// https://www.scoop.it/dev/api/1/urls#se
+#[derive(Debug, Serialize)]
+pub struct CreateSuggestionEngineRequest {
+    pub name: String,
+    pub r#type: SuggestionEngineType,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum CreateSuggestionEngineResponse {
+    Ok { suggestion_engine: SuggestionEngine },
+    Err { error: String },
+}
+
+impl UpdateRequest for CreateSuggestionEngineRequest {
+    type Response = CreateSuggestionEngineResponse;
+
+    type Output = SuggestionEngine;
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "se".into()
+    }
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+}
+
+impl TryFrom<CreateSuggestionEngineResponse> for SuggestionEngine {
+    type Error = anyhow::Error;
+
+    fn try_from(value: CreateSuggestionEngineResponse) -> Result<Self, Self::Error> {
+        match value {
+            CreateSuggestionEngineResponse::Ok { suggestion_engine } => Ok(suggestion_engine),
+            CreateSuggestionEngineResponse::Err { error } => {
+                Err(anyhow!("Server returned an error: {error}"))
+            }
+        }
+    }
+}
+
+/// Rename a suggestion engine
+///
+/// https://www.scoop.it/dev/api/1/urls#se_id
+#[derive(Debug, Serialize)]
+pub struct UpdateSuggestionEngineRequest {
+    #[serde(skip)]
+    pub suggestion_engine_id: i64,
+    pub name: String,
+}
+
+impl UpdateRequest for UpdateSuggestionEngineRequest {
+    type Response = EmptyUpdateResponse;
+
+    type Output = ();
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("se/{}", self.suggestion_engine_id).into()
+    }
+}
+
+/// Delete a suggestion engine
+///
+/// https://www.scoop.it/dev/api/1/urls#se_id
+#[derive(Debug, Serialize)]
+pub struct DeleteSuggestionEngineRequest {
+    #[serde(skip)]
+    pub suggestion_engine_id: i64,
+}
+
+impl UpdateRequest for DeleteSuggestionEngineRequest {
+    type Response = EmptyUpdateResponse;
+
+    type Output = ();
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("se/{}", self.suggestion_engine_id).into()
+    }
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn is_idempotent(&self) -> bool {
+        true
+    }
+}
+
+/// Create a saved search in a suggestion engine
+///
+/// https://www.scoop.it/dev/api/1/urls#se_saved-searches
+#[derive(Debug, Serialize)]
+pub struct CreateSuggestionEngineSavedSearchRequest {
+    #[serde(skip)]
+    pub suggestion_engine_id: i64,
+    pub name: String,
+    pub query: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum CreateSuggestionEngineSavedSearchResponse {
+    Ok {
+        saved_search: SuggestionEngineSavedSearch,
+    },
+    Err {
+        error: String,
+    },
+}
+
+impl UpdateRequest for CreateSuggestionEngineSavedSearchRequest {
+    type Response = CreateSuggestionEngineSavedSearchResponse;
+
+    type Output = SuggestionEngineSavedSearch;
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("se/{}/saved-searches", self.suggestion_engine_id).into()
+    }
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+}
+
+impl TryFrom<CreateSuggestionEngineSavedSearchResponse> for SuggestionEngineSavedSearch {
+    type Error = anyhow::Error;
+
+    fn try_from(value: CreateSuggestionEngineSavedSearchResponse) -> Result<Self, Self::Error> {
+        match value {
+            CreateSuggestionEngineSavedSearchResponse::Ok { saved_search } => Ok(saved_search),
+            CreateSuggestionEngineSavedSearchResponse::Err { error } => {
+                Err(anyhow!("Server returned an error: {error}"))
+            }
+        }
+    }
+}
+
+/// Update a saved search of a suggestion engine
+///
+/// https://www.scoop.it/dev/api/1/urls#se_saved-searches_id
+#[derive(Debug, Serialize)]
+pub struct UpdateSuggestionEngineSavedSearchRequest {
+    #[serde(skip)]
+    pub suggestion_engine_id: i64,
+    #[serde(skip)]
+    pub saved_search_id: i64,
+    pub name: Option<String>,
+    pub query: Option<String>,
+}
+
+impl UpdateRequest for UpdateSuggestionEngineSavedSearchRequest {
+    type Response = EmptyUpdateResponse;
+
+    type Output = ();
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "se/{}/saved-searches/{}",
+            self.suggestion_engine_id, self.saved_search_id
+        )
+        .into()
+    }
+}
+
+/// Delete a saved search of a suggestion engine
+///
+/// https://www.scoop.it/dev/api/1/urls#se_saved-searches_id
+#[derive(Debug, Serialize)]
+pub struct DeleteSuggestionEngineSavedSearchRequest {
+    #[serde(skip)]
+    pub suggestion_engine_id: i64,
+    #[serde(skip)]
+    pub saved_search_id: i64,
+}
+
+impl UpdateRequest for DeleteSuggestionEngineSavedSearchRequest {
+    type Response = EmptyUpdateResponse;
+
+    type Output = ();
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "se/{}/saved-searches/{}",
+            self.suggestion_engine_id, self.saved_search_id
+        )
+        .into()
+    }
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn is_idempotent(&self) -> bool {
+        true
+    }
+}
+
 /// Get manual user sources of a suggestion engine.
 ///
 /// https://www.scoop.it/dev/api/1/urls#se_sources
@@ -543,6 +2205,67 @@ impl TryFrom<GetSuggestionEngineSourcesResponse> for Vec<Source> {
     }
 }
 
+/// Get the content suggested by a suggestion engine, optionally filtered by source or saved search.
+///
+/// https://www.scoop.it/dev/api/1/urls#se_content
+#[derive(Debug, Serialize, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetSuggestionEngineContentRequest {
+    #[serde(skip)]
+    pub suggestion_engine_id: i64,
+    /// only return content produced by this source
+    pub source_id: Option<i64>,
+    /// only return content produced by this saved search
+    pub saved_search_id: Option<i64>,
+    /// number of items per page (defaults to 50)
+    pub count: Option<u32>,
+    /// page number of items to retrieve, the first page is 0
+    pub page: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum GetSuggestionEngineContentResponse {
+    Ok { suggestions: Vec<SuggestedContent> },
+    Err { error: String },
+}
+
+impl GetRequest for GetSuggestionEngineContentRequest {
+    type Response = GetSuggestionEngineContentResponse;
+
+    type Output = Vec<SuggestedContent>;
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("se/{}/content", self.suggestion_engine_id).into()
+    }
+}
+
+impl PaginatedRequest for GetSuggestionEngineContentRequest {
+    type Item = SuggestedContent;
+
+    fn split_page(&self, output: Self::Output) -> (Vec<Self::Item>, bool) {
+        let has_more = has_more_by_count(output.len(), self.count, 50);
+        (output, has_more)
+    }
+
+    fn next_page(&mut self) {
+        self.page = following_page(self.page);
+    }
+}
+
+impl TryFrom<GetSuggestionEngineContentResponse> for Vec<SuggestedContent> {
+    type Error = anyhow::Error;
+
+    fn try_from(value: GetSuggestionEngineContentResponse) -> Result<Self, Self::Error> {
+        match value {
+            GetSuggestionEngineContentResponse::Ok { suggestions } => Ok(suggestions),
+            GetSuggestionEngineContentResponse::Err { error } => {
+                Err(anyhow!("Server returned an error: {error}"))
+            }
+        }
+    }
+}
+
 #[derive(Deserialize, Debug)]
 #[serde(untagged)]
 pub enum EmptyUpdateResponse {
@@ -601,6 +2324,10 @@ impl UpdateRequest for DeleteSuggestionEngineSourceRequest {
     fn method(&self) -> Method {
         Method::DELETE
     }
+
+    fn is_idempotent(&self) -> bool {
+        true
+    }
 }
 
 /// Update a manually source from a suggestion engine
@@ -722,7 +2449,7 @@ impl TryFrom<GetTopicGroupResponse> for TopicGroup {
 /// Get the data about a topic group
 ///
 /// https://www.scoop.it/dev/api/1/urls#compilation
-#[derive(Serialize, Debug, Default)]
+#[derive(Serialize, Debug, Default, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct GetCompilationRequest {
     ///  method used for sorting posts (GetCompilationSort::Rss if not specified)
@@ -747,7 +2474,7 @@ pub struct GetCompilationRequest {
     pub get_stats_for_topic: Option<bool>,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, Clone)]
 pub enum GetCompilationSort {
     /// posts are ordered like in the RSS feed
     #[serde(rename = "rss")]
@@ -774,6 +2501,20 @@ impl GetRequest for GetCompilationRequest {
     }
 }
 
+impl PaginatedRequest for GetCompilationRequest {
+    type Item = Post;
+
+    fn split_page(&self, output: Self::Output) -> (Vec<Self::Item>, bool) {
+        // the number of posts of a compilation is unknown: stop on the first empty page
+        let has_more = !output.is_empty();
+        (output, has_more)
+    }
+
+    fn next_page(&mut self) {
+        self.page = following_page(self.page);
+    }
+}
+
 impl TryFrom<GetCompilationResponse> for Vec<Post> {
     type Error = anyhow::Error;
 
@@ -0,0 +1,83 @@
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+
+use reqwest::{Response, StatusCode};
+
+/// Policy used by the client to retry requests failing with a transient error.
+///
+/// A request is retried when it fails to connect, times out or when the server responds with one
+/// of `retriable_statuses`. The delay between two attempts grows exponentially from `base_delay`
+/// up to `max_delay`.
+///
+/// `GET` requests are always retried according to the policy, update requests only if they are
+/// idempotent (see `UpdateRequest::is_idempotent`).
+///
+/// Requests rejected because of rate limiting (HTTP 429) are retried whatever the request after the
+/// delay asked by the server, unless this delay is longer than `max_delay`.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    /// maximum number of attempts of a request, including the first one (`1` disables retries)
+    pub max_attempts: u32,
+    /// delay before the first retry, doubled at each following retry
+    pub base_delay: Duration,
+    /// upper bound of the delay between two attempts
+    pub max_delay: Duration,
+    /// if true, the actual delay is picked randomly between zero and the computed delay so
+    /// concurrent clients do not retry all at once
+    pub jitter: bool,
+    /// HTTP statuses considered as transient failures
+    pub retriable_statuses: Vec<StatusCode>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            jitter: true,
+            retriable_statuses: vec![
+                StatusCode::INTERNAL_SERVER_ERROR,
+                StatusCode::BAD_GATEWAY,
+                StatusCode::SERVICE_UNAVAILABLE,
+                StatusCode::GATEWAY_TIMEOUT,
+            ],
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries.
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    /// The delay to wait before the given retry (the first retry is `1`).
+    pub fn delay(&self, retry: u32) -> Duration {
+        let delay = self
+            .base_delay
+            .checked_mul(1 << retry.saturating_sub(1).min(31))
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay);
+        if self.jitter {
+            // std hasher keys are randomly seeded, good enough for jitter
+            let random = RandomState::new().build_hasher().finish();
+            delay.mul_f64((random % 1000) as f64 / 1000.0)
+        } else {
+            delay
+        }
+    }
+
+    pub(crate) fn is_transient_failure(&self, response: &reqwest::Result<Response>) -> bool {
+        match response {
+            Ok(response) => self.retriable_statuses.contains(&response.status()),
+            Err(e) => e.is_connect() || e.is_timeout() || e.is_request(),
+        }
+    }
+}
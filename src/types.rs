@@ -57,6 +57,33 @@ pub struct Stats {
     pub v: i32,
     pub vp: i32,
 }
+/// Statistics of a topic over a time range.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TopicStats {
+    pub topic_id: u64,
+    /// one entry per day of the requested range
+    pub days: Vec<TopicStatsDay>,
+    /// present when post stats are requested
+    pub posts: Option<Vec<PostStats>>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TopicStatsDay {
+    /// start of the day (in millis from unix epoch)
+    pub date: i64,
+    pub views: u32,
+    pub visitors: u32,
+    pub clicks: u32,
+    pub shares: u32,
+}
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PostStats {
+    pub post_id: i64,
+    pub page_views: u32,
+    pub page_clicks: u32,
+}
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Post {
@@ -82,6 +109,8 @@ pub struct Post {
     pub image_position: Option<String>,
     pub tags: Option<Vec<String>>,
     pub comments_count: u32,
+    /// present when comments are requested (`ncomments` parameter)
+    pub comments: Option<Vec<Comment>>,
     pub page_views: Option<u32>,
     pub page_clicks: Option<u32>,
     pub author: Option<User>,
@@ -95,6 +124,121 @@ pub struct Post {
     // note that this may change in the future to an opaque json object
     pub metadata: Option<HashMap<String, String>>,
 }
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Comment {
+    pub id: i64,
+    pub author: User,
+    pub content: String,
+    pub html_content: Option<String>,
+    pub date: i64,
+}
+
+/// A notification of the current user.
+///
+/// Kinds of notification unknown to this client are deserialized as `Notification::Unknown`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub enum Notification {
+    /// a user started following a topic of the current user
+    #[serde(rename_all = "camelCase")]
+    NewFollower {
+        date: i64,
+        user: Box<User>,
+        topic: Option<Box<Topic>>,
+    },
+    /// a post has been suggested to a topic of the current user
+    #[serde(rename_all = "camelCase")]
+    Suggestion {
+        date: i64,
+        suggested_by: Box<User>,
+        post: Box<Post>,
+    },
+    /// a post of the current user has been commented
+    #[serde(rename_all = "camelCase")]
+    Comment {
+        date: i64,
+        comment: Box<Comment>,
+        post: Box<Post>,
+    },
+    /// a post of the current user has been thanked
+    #[serde(rename_all = "camelCase")]
+    Thank {
+        date: i64,
+        user: Box<User>,
+        post: Box<Post>,
+    },
+    /// a post of the current user has been rescooped
+    #[serde(rename_all = "camelCase")]
+    Rescoop {
+        date: i64,
+        user: Box<User>,
+        post: Box<Post>,
+    },
+    #[serde(other)]
+    Unknown,
+}
+
+/// The scoop.it object a url points to.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub enum ResolvedObject {
+    Topic { id: u64 },
+    Post { id: i64 },
+    User { id: u64 },
+}
+
+/// Engagement counters of a post, returned by thank and reaction actions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PostCounters {
+    pub thanks_count: u32,
+    pub reactions_count: u32,
+}
+
+impl From<&Post> for PostCounters {
+    fn from(post: &Post) -> Self {
+        Self {
+            thanks_count: post.thanks_count,
+            reactions_count: post.reactions_count,
+        }
+    }
+}
+
+/// A sharer (social account connection) a post can be shared on.
+///
+/// Used by the `shareOn` parameter of post actions.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SharerTarget {
+    /// the kind of sharer: "twitter", "linkedin", "facebook_page"...
+    pub sharer_id: String,
+    /// the id of the connection of the user to the sharer
+    pub cnx_id: i64,
+}
+/// A sharer of the current user: a connection to a social account.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Sharer {
+    /// the kind of sharer: "twitter", "linkedin", "facebook_page"...
+    pub sharer_id: String,
+    /// display name of the kind of sharer
+    pub sharer_name: String,
+    /// the id of the connection of the user to the sharer
+    pub cnx_id: i64,
+    /// the name of the connected account
+    pub cnx_name: String,
+}
+
+impl Sharer {
+    /// The target to use in `shareOn` parameters to share on this sharer.
+    pub fn target(&self) -> SharerTarget {
+        SharerTarget {
+            sharer_id: self.sharer_id.clone(),
+            cnx_id: self.cnx_id,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct SearchResults {
     pub users: Option<Vec<User>>,
@@ -103,6 +247,14 @@ pub struct SearchResults {
     pub total_found: i32,
 }
 
+/// An item of the results of a search.
+#[derive(Debug, Clone)]
+pub enum SearchResult {
+    User(Box<User>),
+    Topic(Box<Topic>),
+    Post(Box<Post>),
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct RecipientsList {
@@ -111,6 +263,25 @@ pub struct RecipientsList {
     pub emails: Vec<String>,
 }
 
+/// A newsletter sent through the API.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct NewsletterSend {
+    pub id: i64,
+    pub status: NewsletterSendStatus,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum NewsletterSendStatus {
+    Queued,
+    Sending,
+    Sent,
+    Failed,
+    #[serde(other)]
+    Unknown,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum SuggestionEngineType {
@@ -129,8 +300,24 @@ pub struct SuggestionEngine {
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct SuggestionEngineSavedSearch {
-    id: i64,
-    name: String,
+    pub id: i64,
+    pub name: String,
+}
+
+/// A content suggested by a suggestion engine, not yet curated in a topic.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SuggestedContent {
+    pub id: String,
+    pub title: String,
+    pub content: Option<String>,
+    pub url: String,
+    pub image_url: Option<String>,
+    pub publication_date: Option<i64>,
+    /// the source of the suggestion engine that produced this content
+    pub source_id: Option<i64>,
+    /// the saved search of the suggestion engine that produced this content
+    pub saved_search_id: Option<i64>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
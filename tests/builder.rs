@@ -0,0 +1,80 @@
+use std::time::Duration;
+
+use common::{access_token_store, response, ScriptedServer};
+use scoopit_api::{
+    reqwest::header::{HeaderName, HeaderValue},
+    GetRecipientsListRequest, RateLimit, RetryPolicy, ScoopitAPIClient,
+};
+
+mod common;
+
+#[tokio::test]
+async fn test_build_client() {
+    let server = ScriptedServer::start(vec![response(200, &[], r#"{"list": []}"#)]).await;
+    let client = server
+        .client_builder()
+        .connect_timeout(Duration::from_secs(1))
+        .timeout(Duration::from_secs(10))
+        .app_name("my-app/1.0")
+        .default_header(
+            HeaderName::from_static("x-request-source"),
+            HeaderValue::from_static("tests"),
+        )
+        .retry_policy(RetryPolicy::none())
+        .rate_limit(RateLimit::per_second(5))
+        .build(access_token_store())
+        .unwrap();
+    client
+        .get(GetRecipientsListRequest::default())
+        .await
+        .unwrap();
+
+    let requests = server.requests();
+    assert_eq!(1, requests.len());
+    let request = requests[0].to_lowercase();
+    assert!(request.contains("\r\nuser-agent: reqwest (scoopit-api-rs) my-app/1.0\r\n"));
+    assert!(request.contains("\r\nx-request-source: tests\r\n"));
+    assert!(request.contains("\r\nauthorization: bearer token\r\n"));
+}
+
+#[tokio::test]
+async fn test_build_client_with_http_client() {
+    let server = ScriptedServer::start(vec![response(200, &[], r#"{"list": []}"#)]).await;
+    let client = server
+        .client_builder()
+        .http_client(
+            scoopit_api::reqwest::Client::builder()
+                .user_agent("my-service")
+                .build()
+                .unwrap(),
+        )
+        .build(access_token_store())
+        .unwrap();
+    client
+        .get(GetRecipientsListRequest::default())
+        .await
+        .unwrap();
+    assert!(server.requests()[0]
+        .to_lowercase()
+        .contains("\r\nuser-agent: my-service\r\n"));
+
+    // settings of the builder would be silently ignored by the existing client
+    assert!(ScoopitAPIClient::builder()
+        .http_client(scoopit_api::reqwest::Client::new())
+        .app_name("my-app/1.0")
+        .build(access_token_store())
+        .is_err());
+    assert!(ScoopitAPIClient::builder()
+        .timeout(Duration::from_secs(10))
+        .http_client(scoopit_api::reqwest::Client::new())
+        .build(access_token_store())
+        .is_err());
+}
+
+#[test]
+fn test_build_client_with_invalid_app_name() {
+    assert!(ScoopitAPIClient::builder()
+        .app_name("invalid\nname")
+        .build(access_token_store())
+        .is_err());
+}
@@ -0,0 +1,130 @@
+//! A local HTTP server answering scripted responses, to test the client offline.
+#![allow(dead_code)]
+
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+};
+
+use serde_json::{json, Value};
+
+use scoopit_api::{url::Url, AccessToken, AccessTokenStore, ScoopitAPI, ScoopitAPIClientBuilder};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+};
+
+pub struct ScriptedServer {
+    pub url: Url,
+    requests: Arc<Mutex<Vec<String>>>,
+}
+
+impl ScriptedServer {
+    /// Start a server answering the given responses in order, one per request.
+    pub async fn start(responses: Vec<String>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = Url::parse(&format!("http://{}", listener.local_addr().unwrap())).unwrap();
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let mut responses = VecDeque::from(responses);
+        let received = requests.clone();
+        tokio::spawn(async move {
+            while let Some(response) = responses.pop_front() {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let request = read_request(&mut stream).await;
+                received.lock().unwrap().push(request);
+                stream.write_all(response.as_bytes()).await.unwrap();
+                stream.shutdown().await.unwrap();
+            }
+        });
+        Self { url, requests }
+    }
+
+    /// The requests received so far (request line, headers and body).
+    pub fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// A client builder targeting the server.
+    pub fn client_builder(&self) -> ScoopitAPIClientBuilder {
+        ScoopitAPIClientBuilder::new().scoopit_api(ScoopitAPI::custom(self.url.clone()).unwrap())
+    }
+}
+
+/// An access token store with a never expiring token.
+pub fn access_token_store() -> AccessTokenStore {
+    AccessTokenStore::new(
+        AccessToken::new("token".to_string()),
+        ScoopitAPI::default(),
+        scoopit_api::reqwest::Client::new(),
+        "client_id".to_string(),
+        "client_secret".to_string(),
+    )
+}
+
+/// The post of `samples/post.json`.
+pub fn post_json() -> Value {
+    let sample: Value = serde_json::from_str(include_str!("../samples/post.json")).unwrap();
+    sample["post"].clone()
+}
+
+/// A topic with the given curated posts.
+pub fn topic_json(posts: Vec<Value>, curated_post_count: u64) -> Value {
+    json!({
+        "id": 1234,
+        "smallImageUrl": "https://img.scoop.it/small.jpg",
+        "mediumImageUrl": "https://img.scoop.it/medium.jpg",
+        "imageUrl": "https://img.scoop.it/image.jpg",
+        "largeImageUrl": "https://img.scoop.it/large.jpg",
+        "name": "Best of photojournalism",
+        "shortName": "best-of-photojournalism",
+        "url": "https://www.scoop.it/topic/best-of-photojournalism",
+        "lang": "en",
+        "curatedPostCount": curated_post_count,
+        "isPrivate": false,
+        "curatedPosts": posts,
+    })
+}
+
+/// A raw HTTP response closing the connection.
+pub fn response(status: u16, headers: &[(&str, &str)], body: &str) -> String {
+    let mut response = format!(
+        "HTTP/1.1 {status} Scripted\r\nConnection: close\r\nContent-Type: application/json\r\nContent-Length: {}\r\n",
+        body.len()
+    );
+    for (name, value) in headers {
+        response.push_str(&format!("{name}: {value}\r\n"));
+    }
+    response.push_str("\r\n");
+    response.push_str(body);
+    response
+}
+
+async fn read_request(stream: &mut TcpStream) -> String {
+    let mut request = Vec::new();
+    let mut buffer = [0; 4096];
+    loop {
+        let read = stream.read(&mut buffer).await.unwrap();
+        if read == 0 {
+            break;
+        }
+        request.extend_from_slice(&buffer[..read]);
+        let text = String::from_utf8_lossy(&request);
+        if let Some(headers_end) = text.find("\r\n\r\n") {
+            let content_length = text[..headers_end]
+                .lines()
+                .find_map(|line| {
+                    let (name, value) = line.split_once(':')?;
+                    if name.eq_ignore_ascii_case("content-length") {
+                        value.trim().parse::<usize>().ok()
+                    } else {
+                        None
+                    }
+                })
+                .unwrap_or(0);
+            if request.len() >= headers_end + 4 + content_length {
+                break;
+            }
+        }
+    }
+    String::from_utf8_lossy(&request).into_owned()
+}
@@ -0,0 +1,57 @@
+use futures::TryStreamExt;
+use serde_json::json;
+
+use common::{access_token_store, post_json, response, topic_json, ScriptedServer};
+use scoopit_api::GetTopicRequest;
+
+mod common;
+
+fn topic_response(posts: usize, curated_post_count: u64) -> String {
+    let body = json!({ "topic": topic_json(vec![post_json(); posts], curated_post_count) });
+    response(200, &[], &body.to_string())
+}
+
+#[tokio::test]
+async fn test_topic_posts_stops_after_last_page() {
+    // 5 posts with pages of 2: the stream ends after the short third page
+    let server = ScriptedServer::start(vec![
+        topic_response(2, 5),
+        topic_response(2, 5),
+        topic_response(1, 5),
+        topic_response(2, 5),
+    ])
+    .await;
+    let client = server.client_builder().build(access_token_store()).unwrap();
+    let posts = client
+        .topic_posts(GetTopicRequest {
+            id: Some(1234),
+            curated: Some(2),
+            ..Default::default()
+        })
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+    assert_eq!(5, posts.len());
+
+    let requests = server.requests();
+    assert_eq!(3, requests.len());
+    assert!(requests[0].starts_with("GET /api/1/topic?id=1234&curated=2&"));
+    assert!(requests[2].contains("&page=2"));
+}
+
+#[tokio::test]
+async fn test_topic_posts_stops_on_empty_page() {
+    let server = ScriptedServer::start(vec![topic_response(0, 10), topic_response(0, 10)]).await;
+    let client = server.client_builder().build(access_token_store()).unwrap();
+    let posts = client
+        .topic_posts(GetTopicRequest {
+            id: Some(1234),
+            curated: Some(0),
+            ..Default::default()
+        })
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+    assert!(posts.is_empty());
+    assert_eq!(1, server.requests().len());
+}
@@ -1,10 +1,31 @@
+use common::{post_json, topic_json};
 use reqwest::Method;
 use scoopit_api::{
-    serde_qs, types::SourceTypeData, CreateSuggestionEngineSourceRequest,
-    DeleteSuggestionEngineSourceRequest, GetSuggestionEngineSourcesRequest, UpdateRequest,
-    UpdateSuggestionEngineSourceRequest,
+    serde_qs,
+    types::{
+        Post, SearchResults, SharerTarget, SourceTypeData, SuggestedContent, SuggestionEngineType,
+        Topic, User,
+    },
+    AcceptPostRequest, AddPostReactionRequest, CommentPostRequest, CreatePostRequest,
+    CreateRecipientsListRequest, CreateSuggestionEngineRequest,
+    CreateSuggestionEngineSavedSearchRequest, CreateSuggestionEngineSourceRequest,
+    CreateTopicRequest, DeletePostCommentRequest, DeletePostRequest, DeleteRecipientsListRequest,
+    DeleteSuggestionEngineRequest, DeleteSuggestionEngineSavedSearchRequest,
+    DeleteSuggestionEngineSourceRequest, DeleteTopicRequest, DeleteTopicTagRequest,
+    EditPostRequest, EditTopicRequest, FollowTopicRequest, ForwardPostRequest,
+    GetCompilationRequest, GetPostRequest, GetRequest, GetScheduledPostsRequest,
+    GetSuggestionEngineContentRequest, GetSuggestionEngineSourcesRequest, GetTopicFollowersRequest,
+    GetTopicRequest, GetTopicStatsRequest, GetUserFollowersRequest, GetUserFollowingsRequest,
+    MergeTopicTagsRequest, PaginatedRequest, PinPostRequest, RefusePostRequest,
+    RemovePostReactionRequest, RenameTopicTagRequest, ReschedulePostRequest, RescoopPostRequest,
+    ResolveUrlRequest, ScheduledPostsResponse, SearchRequest, SendNewsletterRequest,
+    SharePostRequest, ThankPostRequest, UnfollowTopicRequest, UnpinPostRequest, UnthankPostRequest,
+    UpdateRecipientsListRequest, UpdateRequest, UpdateSuggestionEngineRequest,
+    UpdateSuggestionEngineSavedSearchRequest, UpdateSuggestionEngineSourceRequest,
 };
 
+mod common;
+
 #[test]
 fn test_source_requests_serialization() {
     let get_source = GetSuggestionEngineSourcesRequest {
@@ -57,3 +78,752 @@ fn test_source_requests_serialization() {
     );
     assert_eq!(Method::PUT, create_twitter_source.method());
 }
+
+#[test]
+fn test_get_post_request_serialization() {
+    let get_post = GetPostRequest {
+        id: 4137629012,
+        ..Default::default()
+    };
+    assert_eq!(
+        "id=4137629012&ncomments=0&getTags=true",
+        serde_qs::to_string(&get_post).expect("This must be serializable")
+    );
+}
+
+#[test]
+fn test_post_requests_serialization() {
+    let create_post = CreatePostRequest {
+        topic_id: 5678,
+        url: Some("https://www.lemonde.fr/une.html".into()),
+        title: Some("Le Monde à la une".into()),
+        tags: Some(vec!["news".into(), "france".into()]),
+        share_on: Some(vec![SharerTarget {
+            sharer_id: "twitter".into(),
+            cnx_id: 12345,
+        }]),
+        ..Default::default()
+    };
+    assert_eq!("post", create_post.endpoint());
+    assert_eq!(
+        "action=create&topicId=5678&url=https%3A%2F%2Fwww.lemonde.fr%2Fune.html&title=Le+Monde+%C3%A0+la+une&tag=news&tag=france&shareOn=%5B%7B%22sharerId%22%3A%22twitter%22%2C%22cnxId%22%3A12345%7D%5D",
+        String::from_utf8_lossy(&create_post.body().unwrap())
+    );
+    assert_eq!(Method::POST, create_post.method());
+
+    let edit_post = EditPostRequest {
+        id: 4137629012,
+        insight: Some("Must read".into()),
+        ..Default::default()
+    };
+    assert_eq!(
+        "action=edit&id=4137629012&insight=Must+read",
+        String::from_utf8_lossy(&edit_post.body().unwrap())
+    );
+
+    let rescoop_post = RescoopPostRequest {
+        id: 4137629012,
+        dest_topic_id: 5678,
+        insight: Some("Worth a read".into()),
+        ..Default::default()
+    };
+    assert_eq!("post", rescoop_post.endpoint());
+    assert_eq!(
+        "action=rescoop&id=4137629012&destTopicId=5678&insight=Worth+a+read",
+        String::from_utf8_lossy(&rescoop_post.body().unwrap())
+    );
+
+    let pin_post = PinPostRequest { id: 4137629012 };
+    assert_eq!(
+        "action=pin&id=4137629012",
+        String::from_utf8_lossy(&pin_post.body().unwrap())
+    );
+    let unpin_post = UnpinPostRequest { id: 4137629012 };
+    assert_eq!(
+        "action=unpin&id=4137629012",
+        String::from_utf8_lossy(&unpin_post.body().unwrap())
+    );
+
+    let delete_post = DeletePostRequest { id: 4137629012 };
+    assert_eq!(
+        "action=delete&id=4137629012",
+        String::from_utf8_lossy(&delete_post.body().unwrap())
+    );
+}
+
+#[test]
+fn test_curable_post_requests_serialization() {
+    let accept_post = AcceptPostRequest {
+        id: 4137629012,
+        insight: Some("Great find".into()),
+        tags: Some(vec!["news".into()]),
+        ..Default::default()
+    };
+    assert_eq!("post", accept_post.endpoint());
+    assert_eq!(
+        "action=accept&id=4137629012&insight=Great+find&tag=news",
+        String::from_utf8_lossy(&accept_post.body().unwrap())
+    );
+
+    let refuse_post = RefusePostRequest {
+        id: 4137629012,
+        reason: None,
+    };
+    assert_eq!(
+        "action=refuse&id=4137629012",
+        String::from_utf8_lossy(&refuse_post.body().unwrap())
+    );
+
+    let forward_post = ForwardPostRequest {
+        id: 4137629012,
+        topic_id: 5678,
+    };
+    assert_eq!(
+        "action=forward&id=4137629012&topicId=5678",
+        String::from_utf8_lossy(&forward_post.body().unwrap())
+    );
+}
+
+#[test]
+fn test_scheduled_posts_requests_serialization() {
+    let get_scheduled =
+        GetScheduledPostsRequest::with_url_name("best-of-photojournalism", 1700000000000);
+    assert_eq!(
+        "urlName=best-of-photojournalism&curated=30&curable=0&ncomments=0&showScheduled=true",
+        serde_qs::to_string(&get_scheduled).expect("This must be serializable")
+    );
+    let get_scheduled = GetScheduledPostsRequest {
+        count: Some(10),
+        page: Some(2),
+        ..GetScheduledPostsRequest::with_id(1234, 1700000000000)
+    };
+    assert_eq!(
+        "id=1234&curated=10&page=2&curable=0&ncomments=0&showScheduled=true",
+        serde_qs::to_string(&get_scheduled).expect("This must be serializable")
+    );
+
+    let create_post = CreatePostRequest {
+        topic_id: 5678,
+        url: Some("https://www.lemonde.fr/une.html".into()),
+        publication_date: Some(1700000000000),
+        ..Default::default()
+    };
+    assert_eq!(
+        "action=create&topicId=5678&url=https%3A%2F%2Fwww.lemonde.fr%2Fune.html&publicationDate=1700000000000",
+        String::from_utf8_lossy(&create_post.body().unwrap())
+    );
+
+    let reschedule_post = ReschedulePostRequest {
+        id: 4137629012,
+        publication_date: 1700000000000,
+    };
+    assert_eq!(
+        "action=reschedule&id=4137629012&publicationDate=1700000000000",
+        String::from_utf8_lossy(&reschedule_post.body().unwrap())
+    );
+}
+
+#[test]
+fn test_comment_requests_serialization() {
+    let comment_post = CommentPostRequest {
+        id: 4137629012,
+        comment_text: "Thanks for sharing!".into(),
+    };
+    assert_eq!("post", comment_post.endpoint());
+    assert_eq!(
+        "action=comment&id=4137629012&commentText=Thanks+for+sharing%21",
+        String::from_utf8_lossy(&comment_post.body().unwrap())
+    );
+
+    let delete_comment = DeletePostCommentRequest {
+        id: 4137629012,
+        comment_id: 98765,
+    };
+    assert_eq!(
+        "action=deleteComment&id=4137629012&commentId=98765",
+        String::from_utf8_lossy(&delete_comment.body().unwrap())
+    );
+}
+
+#[test]
+fn test_engagement_requests_serialization() {
+    let thank_post = ThankPostRequest { id: 4137629012 };
+    assert_eq!("post", thank_post.endpoint());
+    assert_eq!(
+        "action=thank&id=4137629012",
+        String::from_utf8_lossy(&thank_post.body().unwrap())
+    );
+    let unthank_post = UnthankPostRequest { id: 4137629012 };
+    assert_eq!(
+        "action=unthank&id=4137629012",
+        String::from_utf8_lossy(&unthank_post.body().unwrap())
+    );
+
+    let react = AddPostReactionRequest {
+        id: 4137629012,
+        reaction: "love".into(),
+    };
+    assert_eq!(
+        "action=react&id=4137629012&reaction=love",
+        String::from_utf8_lossy(&react.body().unwrap())
+    );
+    let unreact = RemovePostReactionRequest {
+        id: 4137629012,
+        reaction: "love".into(),
+    };
+    assert_eq!(
+        "action=unreact&id=4137629012&reaction=love",
+        String::from_utf8_lossy(&unreact.body().unwrap())
+    );
+}
+
+#[test]
+fn test_share_post_request_serialization() {
+    let share_post = SharePostRequest {
+        id: 4137629012,
+        text: Some("Must read".into()),
+        share_on: vec![SharerTarget {
+            sharer_id: "linkedin".into(),
+            cnx_id: 67890,
+        }],
+    };
+    assert_eq!("post", share_post.endpoint());
+    assert_eq!(
+        "action=share&id=4137629012&text=Must+read&shareOn=%5B%7B%22sharerId%22%3A%22linkedin%22%2C%22cnxId%22%3A67890%7D%5D",
+        String::from_utf8_lossy(&share_post.body().unwrap())
+    );
+}
+
+#[test]
+fn test_topic_requests_serialization() {
+    let create_topic = CreateTopicRequest {
+        name: "Client News".into(),
+        lang: Some("fr".into()),
+        is_private: Some(true),
+        ..Default::default()
+    };
+    assert_eq!("topic", create_topic.endpoint());
+    assert_eq!(
+        "action=create&name=Client+News&lang=fr&isPrivate=true",
+        String::from_utf8_lossy(&create_topic.body().unwrap())
+    );
+
+    let edit_topic = EditTopicRequest {
+        id: 5678,
+        description: Some("All the news of our client".into()),
+        ..Default::default()
+    };
+    assert_eq!(
+        "action=edit&id=5678&description=All+the+news+of+our+client",
+        String::from_utf8_lossy(&edit_topic.body().unwrap())
+    );
+
+    let follow_topic = FollowTopicRequest {
+        url_name: Some("best-of-photojournalism".into()),
+        ..Default::default()
+    };
+    assert_eq!(
+        "action=follow&urlName=best-of-photojournalism",
+        String::from_utf8_lossy(&follow_topic.body().unwrap())
+    );
+    let unfollow_topic = UnfollowTopicRequest {
+        id: Some(5678),
+        ..Default::default()
+    };
+    assert_eq!(
+        "action=unfollow&id=5678",
+        String::from_utf8_lossy(&unfollow_topic.body().unwrap())
+    );
+
+    let delete_topic = DeleteTopicRequest { id: 5678 };
+    assert_eq!(
+        "action=delete&id=5678",
+        String::from_utf8_lossy(&delete_topic.body().unwrap())
+    );
+}
+
+#[test]
+fn test_topic_tag_requests_serialization() {
+    let rename_tag = RenameTopicTagRequest {
+        id: 5678,
+        tag: "afganistan".into(),
+        new_tag: "afghanistan".into(),
+    };
+    assert_eq!("topic", rename_tag.endpoint());
+    assert_eq!(
+        "action=renameTag&id=5678&tag=afganistan&newTag=afghanistan",
+        String::from_utf8_lossy(&rename_tag.body().unwrap())
+    );
+
+    let merge_tags = MergeTopicTagsRequest {
+        id: 5678,
+        tags: vec!["photo".into(), "photos".into()],
+        into: "photography".into(),
+    };
+    assert_eq!(
+        "action=mergeTags&id=5678&tag=photo&tag=photos&into=photography",
+        String::from_utf8_lossy(&merge_tags.body().unwrap())
+    );
+
+    let delete_tag = DeleteTopicTagRequest {
+        id: 5678,
+        tag: "misc".into(),
+    };
+    assert_eq!(
+        "action=deleteTag&id=5678&tag=misc",
+        String::from_utf8_lossy(&delete_tag.body().unwrap())
+    );
+}
+
+#[test]
+fn test_get_topic_stats_request_serialization() {
+    let get_stats = GetTopicStatsRequest {
+        id: 5678,
+        from: Some(1667260800000),
+        to: Some(1669852800000),
+        get_post_stats: true,
+    };
+    assert_eq!("topic-stats", get_stats.endpoint());
+    assert_eq!(
+        "id=5678&from=1667260800000&to=1669852800000&getPostStats=true",
+        serde_qs::to_string(&get_stats).expect("This must be serializable")
+    );
+}
+
+#[test]
+fn test_followers_requests_serialization() {
+    let topic_followers = GetTopicFollowersRequest {
+        url_name: Some("best-of-photojournalism".into()),
+        count: Some(100),
+        page: Some(2),
+        ..Default::default()
+    };
+    assert_eq!("topic/followers", topic_followers.endpoint());
+    assert_eq!(
+        "urlName=best-of-photojournalism&count=100&page=2",
+        serde_qs::to_string(&topic_followers).expect("This must be serializable")
+    );
+
+    let user_followers = GetUserFollowersRequest {
+        short_name: Some("pgassmann".into()),
+        ..Default::default()
+    };
+    assert_eq!("profile/followers", user_followers.endpoint());
+    assert_eq!(
+        "shortName=pgassmann",
+        serde_qs::to_string(&user_followers).expect("This must be serializable")
+    );
+
+    let user_followings = GetUserFollowingsRequest {
+        id: Some(1234),
+        ..Default::default()
+    };
+    assert_eq!("profile/followings", user_followings.endpoint());
+    assert_eq!(
+        "id=1234",
+        serde_qs::to_string(&user_followings).expect("This must be serializable")
+    );
+}
+
+#[test]
+fn test_resolve_url_request_serialization() {
+    let resolve = ResolveUrlRequest {
+        url: "https://sco.lt/5Tz8Qa".into(),
+    };
+    assert_eq!("resolver", resolve.endpoint());
+    assert_eq!(
+        "url=https%3A%2F%2Fsco.lt%2F5Tz8Qa",
+        serde_qs::to_string(&resolve).expect("This must be serializable")
+    );
+}
+
+#[test]
+fn test_recipients_list_requests_serialization() {
+    let create_list = CreateRecipientsListRequest {
+        name: "Clients".into(),
+        emails: vec!["john@example.com".into(), "jane@example.com".into()],
+    };
+    assert_eq!("recipients-list", create_list.endpoint());
+    assert_eq!(
+        "name=Clients&email=john%40example.com&email=jane%40example.com",
+        String::from_utf8_lossy(&create_list.body().unwrap())
+    );
+    assert_eq!(Method::PUT, create_list.method());
+
+    let update_list = UpdateRecipientsListRequest {
+        recipients_list_id: 42,
+        add_emails: Some(vec!["joe@example.com".into()]),
+        remove_emails: Some(vec!["john@example.com".into()]),
+        ..Default::default()
+    };
+    assert_eq!("recipients-list/42", update_list.endpoint());
+    assert_eq!(
+        "addEmail=joe%40example.com&removeEmail=john%40example.com",
+        String::from_utf8_lossy(&update_list.body().unwrap())
+    );
+    assert_eq!(Method::POST, update_list.method());
+
+    let delete_list = DeleteRecipientsListRequest {
+        recipients_list_id: 42,
+    };
+    assert_eq!("recipients-list/42", delete_list.endpoint());
+    assert_eq!("", String::from_utf8_lossy(&delete_list.body().unwrap()));
+    assert_eq!(Method::DELETE, delete_list.method());
+    assert!(delete_list.is_idempotent());
+    assert!(!update_list.is_idempotent());
+}
+
+#[test]
+fn test_send_newsletter_request_serialization() {
+    let send_newsletter = SendNewsletterRequest {
+        subject: "Weekly digest".into(),
+        topic_id: Some(5678),
+        latest: Some(5),
+        recipients_list_id: Some(42),
+        ..Default::default()
+    };
+    assert_eq!("newsletter", send_newsletter.endpoint());
+    assert_eq!(
+        "subject=Weekly+digest&topicId=5678&latest=5&recipientsListId=42",
+        String::from_utf8_lossy(&send_newsletter.body().unwrap())
+    );
+
+    let send_newsletter = SendNewsletterRequest {
+        subject: "Must reads".into(),
+        post_ids: Some(vec![4137629012, 4137629013]),
+        emails: Some(vec!["jane@example.com".into()]),
+        ..Default::default()
+    };
+    assert_eq!(
+        "subject=Must+reads&postId=4137629012&postId=4137629013&email=jane%40example.com",
+        String::from_utf8_lossy(&send_newsletter.body().unwrap())
+    );
+}
+
+#[test]
+fn test_suggestion_engine_requests_serialization() {
+    let create_se = CreateSuggestionEngineRequest {
+        name: "Competitors".into(),
+        r#type: SuggestionEngineType::ContentMonitoring,
+    };
+    assert_eq!("se", create_se.endpoint());
+    assert_eq!(
+        "name=Competitors&type=content_monitoring",
+        String::from_utf8_lossy(&create_se.body().unwrap())
+    );
+    assert_eq!(Method::PUT, create_se.method());
+
+    let update_se = UpdateSuggestionEngineRequest {
+        suggestion_engine_id: 123,
+        name: "Competitors watch".into(),
+    };
+    assert_eq!("se/123", update_se.endpoint());
+    assert_eq!(
+        "name=Competitors+watch",
+        String::from_utf8_lossy(&update_se.body().unwrap())
+    );
+    assert_eq!(Method::POST, update_se.method());
+
+    let delete_se = DeleteSuggestionEngineRequest {
+        suggestion_engine_id: 123,
+    };
+    assert_eq!("se/123", delete_se.endpoint());
+    assert_eq!(Method::DELETE, delete_se.method());
+
+    let create_saved_search = CreateSuggestionEngineSavedSearchRequest {
+        suggestion_engine_id: 123,
+        name: "POTUS".into(),
+        query: "president united states".into(),
+    };
+    assert_eq!("se/123/saved-searches", create_saved_search.endpoint());
+    assert_eq!(
+        "name=POTUS&query=president+united+states",
+        String::from_utf8_lossy(&create_saved_search.body().unwrap())
+    );
+    assert_eq!(Method::PUT, create_saved_search.method());
+
+    let update_saved_search = UpdateSuggestionEngineSavedSearchRequest {
+        suggestion_engine_id: 123,
+        saved_search_id: 456,
+        name: None,
+        query: Some("president usa".into()),
+    };
+    assert_eq!("se/123/saved-searches/456", update_saved_search.endpoint());
+    assert_eq!(
+        "query=president+usa",
+        String::from_utf8_lossy(&update_saved_search.body().unwrap())
+    );
+    assert_eq!(Method::POST, update_saved_search.method());
+
+    let delete_saved_search = DeleteSuggestionEngineSavedSearchRequest {
+        suggestion_engine_id: 123,
+        saved_search_id: 456,
+    };
+    assert_eq!("se/123/saved-searches/456", delete_saved_search.endpoint());
+    assert_eq!(Method::DELETE, delete_saved_search.method());
+}
+
+#[test]
+fn test_get_suggestion_engine_content_request_serialization() {
+    let get_content = GetSuggestionEngineContentRequest {
+        suggestion_engine_id: 123,
+        saved_search_id: Some(456),
+        count: Some(20),
+        ..Default::default()
+    };
+    assert_eq!("se/123/content", get_content.endpoint());
+    assert_eq!(
+        "savedSearchId=456&count=20",
+        serde_qs::to_string(&get_content).expect("This must be serializable")
+    );
+}
+
+fn post(publication_date: i64) -> Post {
+    let mut post: Post = serde_json::from_value(post_json()).unwrap();
+    post.publication_date = Some(publication_date);
+    post
+}
+
+fn topic(posts: Vec<Post>, curated_post_count: u64) -> Topic {
+    let posts = posts
+        .iter()
+        .map(|post| serde_json::to_value(post).unwrap())
+        .collect();
+    serde_json::from_value(topic_json(posts, curated_post_count)).unwrap()
+}
+
+fn users(count: usize) -> Vec<User> {
+    let user: User = serde_json::from_value(serde_json::json!({
+        "id": 5678,
+        "name": "John Doe",
+        "shortName": "john-doe",
+        "url": "https://www.scoop.it/u/john-doe",
+        "smallAvatarUrl": "https://img.scoop.it/small.jpg",
+        "mediumAvatarUrl": "https://img.scoop.it/medium.jpg",
+        "avatarUrl": "https://img.scoop.it/avatar.jpg",
+        "largeAvatarUrl": "https://img.scoop.it/large.jpg",
+    }))
+    .unwrap();
+    vec![user; count]
+}
+
+fn suggestions(count: usize) -> Vec<SuggestedContent> {
+    vec![
+        SuggestedContent {
+            id: "a5c1f0e4b2d3".into(),
+            title: "Le président américain en visite à Paris".into(),
+            content: None,
+            url: "https://www.lemonde.fr/international/visite-paris.html".into(),
+            image_url: None,
+            publication_date: None,
+            source_id: None,
+            saved_search_id: None,
+        };
+        count
+    ]
+}
+
+#[test]
+fn test_paginated_requests() {
+    let mut search = SearchRequest {
+        query: "test".into(),
+        count: Some(2),
+        ..Default::default()
+    };
+    let (items, has_more) = search.split_page(SearchResults {
+        users: None,
+        topics: None,
+        posts: Some(vec![]),
+        total_found: 3,
+    });
+    assert!(items.is_empty());
+    assert!(!has_more);
+    search.next_page();
+    assert_eq!(Some(1), search.page);
+
+    let mut compilation = GetCompilationRequest::default();
+    let (items, has_more) = compilation.split_page(vec![]);
+    assert!(items.is_empty());
+    assert!(!has_more);
+    compilation.next_page();
+    compilation.next_page();
+    assert_eq!(Some(2), compilation.page);
+}
+
+#[test]
+fn test_topic_pagination() {
+    let mut get_topic = GetTopicRequest {
+        id: Some(1234),
+        curated: Some(2),
+        ..Default::default()
+    };
+    // full page, more posts remaining
+    let (posts, has_more) = get_topic.split_page(topic(vec![post(1), post(2)], 5));
+    assert_eq!(2, posts.len());
+    assert!(has_more);
+
+    get_topic.next_page();
+    assert_eq!(Some(1), get_topic.page);
+    // full page, all posts fetched
+    let (_, has_more) = get_topic.split_page(topic(vec![post(1), post(2)], 4));
+    assert!(!has_more);
+
+    get_topic.next_page();
+    // short last page
+    let (posts, has_more) = get_topic.split_page(topic(vec![post(1)], 10));
+    assert_eq!(1, posts.len());
+    assert!(!has_more);
+
+    // a page size of zero must not paginate forever
+    let get_topic = GetTopicRequest {
+        id: Some(1234),
+        curated: Some(0),
+        ..Default::default()
+    };
+    let (posts, has_more) = get_topic.split_page(topic(vec![], 10));
+    assert!(posts.is_empty());
+    assert!(!has_more);
+}
+
+#[test]
+fn test_scheduled_posts_pagination() {
+    let mut get_scheduled = GetScheduledPostsRequest {
+        count: Some(2),
+        ..GetScheduledPostsRequest::with_id(1234, 1000)
+    };
+    let page = |posts, curated_post_count| {
+        let response = serde_json::from_value::<ScheduledPostsResponse>(serde_json::json!({
+            "topic": topic(posts, curated_post_count),
+        }))
+        .unwrap();
+        get_scheduled.split_page(get_scheduled.output(response).unwrap())
+    };
+    let publication_dates =
+        |posts: Vec<Post>| posts.iter().map(|p| p.publication_date).collect::<Vec<_>>();
+
+    // only scheduled posts
+    let (posts, has_more) = page(vec![post(3000), post(2000)], 5);
+    assert_eq!(vec![Some(3000), Some(2000)], publication_dates(posts));
+    assert!(has_more);
+
+    // the page reaches already published posts, the next ones are not scheduled
+    let (posts, has_more) = page(vec![post(1500), post(1000)], 5);
+    assert_eq!(vec![Some(1500)], publication_dates(posts));
+    assert!(!has_more);
+    let (posts, has_more) = page(vec![post(900), post(800)], 5);
+    assert!(posts.is_empty());
+    assert!(!has_more);
+
+    // end of the topic
+    let (posts, has_more) = page(vec![post(2000)], 5);
+    assert_eq!(1, posts.len());
+    assert!(!has_more);
+
+    get_scheduled.next_page();
+    assert_eq!(Some(1), get_scheduled.page);
+}
+
+#[test]
+fn test_search_pagination() {
+    let search = SearchRequest {
+        query: "test".into(),
+        count: Some(2),
+        page: Some(1),
+        ..Default::default()
+    };
+    let results = |total_found| SearchResults {
+        users: Some(users(2)),
+        topics: None,
+        posts: None,
+        total_found,
+    };
+    // pages 0 and 1 contain 4 results
+    let (items, has_more) = search.split_page(results(5));
+    assert_eq!(2, items.len());
+    assert!(has_more);
+    let (_, has_more) = search.split_page(results(4));
+    assert!(!has_more);
+
+    // must not overflow
+    let search = SearchRequest {
+        query: "test".into(),
+        count: Some(u32::MAX),
+        page: Some(u32::MAX),
+        ..Default::default()
+    };
+    let (_, has_more) = search.split_page(results(i32::MAX));
+    assert!(!has_more);
+}
+
+#[test]
+fn test_count_based_pagination() {
+    let followers = GetTopicFollowersRequest {
+        id: Some(1234),
+        count: Some(2),
+        ..Default::default()
+    };
+    assert!(followers.split_page(users(2)).1);
+    assert!(!followers.split_page(users(1)).1);
+    assert!(!GetTopicFollowersRequest::default().split_page(users(49)).1);
+    assert!(GetTopicFollowersRequest::default().split_page(users(50)).1);
+
+    let followers = GetUserFollowersRequest {
+        count: Some(2),
+        ..Default::default()
+    };
+    assert!(followers.split_page(users(2)).1);
+    assert!(!followers.split_page(users(1)).1);
+
+    let followings = GetUserFollowingsRequest {
+        count: Some(2),
+        ..Default::default()
+    };
+    assert!(followings.split_page(users(2)).1);
+    assert!(!followings.split_page(users(1)).1);
+
+    let content = GetSuggestionEngineContentRequest {
+        suggestion_engine_id: 123,
+        count: Some(2),
+        ..Default::default()
+    };
+    let (items, has_more) = content.split_page(suggestions(2));
+    assert_eq!(2, items.len());
+    assert!(has_more);
+    assert!(!content.split_page(suggestions(1)).1);
+
+    // a page size of zero must not paginate forever
+    let count = Some(0);
+    assert!(
+        !GetTopicFollowersRequest {
+            count,
+            ..Default::default()
+        }
+        .split_page(vec![])
+        .1
+    );
+    assert!(
+        !GetUserFollowersRequest {
+            count,
+            ..Default::default()
+        }
+        .split_page(vec![])
+        .1
+    );
+    assert!(
+        !GetUserFollowingsRequest {
+            count,
+            ..Default::default()
+        }
+        .split_page(vec![])
+        .1
+    );
+    assert!(
+        !GetSuggestionEngineContentRequest {
+            suggestion_engine_id: 123,
+            count,
+            ..Default::default()
+        }
+        .split_page(vec![])
+        .1
+    );
+}
@@ -0,0 +1,189 @@
+use std::time::{Duration, Instant};
+
+use common::{access_token_store, response, ScriptedServer};
+use scoopit_api::{
+    DeleteRecipientsListRequest, GetRecipientsListRequest, RateLimit, RetryPolicy,
+    ScoopitAPIClient, UnpinPostRequest,
+};
+
+mod common;
+
+fn fast_retry_policy() -> RetryPolicy {
+    RetryPolicy {
+        base_delay: Duration::from_millis(10),
+        jitter: false,
+        ..Default::default()
+    }
+}
+
+async fn client(server: &ScriptedServer) -> ScoopitAPIClient {
+    server
+        .client_builder()
+        .retry_policy(fast_retry_policy())
+        .build(access_token_store())
+        .unwrap()
+}
+
+#[test]
+fn test_retry_policy_delay() {
+    let policy = RetryPolicy {
+        base_delay: Duration::from_millis(100),
+        max_delay: Duration::from_secs(1),
+        jitter: false,
+        ..Default::default()
+    };
+    assert_eq!(Duration::from_millis(100), policy.delay(1));
+    assert_eq!(Duration::from_millis(200), policy.delay(2));
+    assert_eq!(Duration::from_millis(400), policy.delay(3));
+    assert_eq!(Duration::from_secs(1), policy.delay(5));
+    assert_eq!(Duration::from_secs(1), policy.delay(100));
+
+    let policy = RetryPolicy {
+        jitter: true,
+        ..policy
+    };
+    for retry in 1..10 {
+        assert!(policy.delay(retry) <= Duration::from_secs(1));
+    }
+
+    assert_eq!(1, RetryPolicy::none().max_attempts);
+}
+
+#[test]
+fn test_rate_limit() {
+    let rate_limit = RateLimit::per_second(10);
+    assert_eq!(10, rate_limit.burst);
+    assert_eq!(Duration::from_millis(100), rate_limit.interval);
+
+    let rate_limit = RateLimit::per_minute(30);
+    assert_eq!(30, rate_limit.burst);
+    assert_eq!(Duration::from_secs(2), rate_limit.interval);
+
+    assert_eq!(1, RateLimit::per_second(0).burst);
+}
+
+#[tokio::test]
+async fn test_get_retried_on_transient_failure() {
+    let server = ScriptedServer::start(vec![
+        response(502, &[], ""),
+        response(503, &[], ""),
+        response(200, &[], r#"{"list": []}"#),
+    ])
+    .await;
+    let lists = client(&server)
+        .await
+        .get(GetRecipientsListRequest::default())
+        .await
+        .unwrap();
+    assert!(lists.is_empty());
+    assert_eq!(3, server.requests().len());
+}
+
+#[tokio::test]
+async fn test_get_fails_once_attempts_run_out() {
+    let server = ScriptedServer::start(vec![
+        response(502, &[], ""),
+        response(502, &[], ""),
+        response(502, &[], ""),
+    ])
+    .await;
+    let error = client(&server)
+        .await
+        .get(GetRecipientsListRequest::default())
+        .await
+        .unwrap_err();
+    assert!(!error.is_not_found());
+    assert_eq!(3, server.requests().len());
+}
+
+#[tokio::test]
+async fn test_non_idempotent_update_not_retried() {
+    let server = ScriptedServer::start(vec![
+        response(502, &[], ""),
+        response(200, &[], r#"{"success": true}"#),
+    ])
+    .await;
+    client(&server)
+        .await
+        .update(UnpinPostRequest { id: 1234 })
+        .await
+        .unwrap_err();
+    assert_eq!(1, server.requests().len());
+    assert!(server.requests()[0].starts_with("POST /api/1/post "));
+}
+
+#[tokio::test]
+async fn test_idempotent_update_retried() {
+    let server = ScriptedServer::start(vec![
+        response(504, &[], ""),
+        response(200, &[], r#"{"success": true}"#),
+    ])
+    .await;
+    client(&server)
+        .await
+        .update(DeleteRecipientsListRequest {
+            recipients_list_id: 1234,
+        })
+        .await
+        .unwrap();
+    assert_eq!(2, server.requests().len());
+    assert!(server.requests()[1].starts_with("DELETE /api/1/recipients-list/1234 "));
+}
+
+#[tokio::test]
+async fn test_rate_limited_request_retried_after_delay() {
+    let server = ScriptedServer::start(vec![
+        response(429, &[("Retry-After", "1")], ""),
+        response(200, &[], r#"{"success": true}"#),
+    ])
+    .await;
+    let start = Instant::now();
+    // rate limited requests are retried even if not idempotent
+    client(&server)
+        .await
+        .update(UnpinPostRequest { id: 1234 })
+        .await
+        .unwrap();
+    assert!(start.elapsed() >= Duration::from_secs(1));
+    assert_eq!(2, server.requests().len());
+}
+
+#[tokio::test]
+async fn test_rate_limited_once_attempts_run_out() {
+    let server = ScriptedServer::start(vec![
+        response(429, &[], ""),
+        response(429, &[("Retry-After", "7")], ""),
+    ])
+    .await;
+    let error = server
+        .client_builder()
+        .retry_policy(RetryPolicy {
+            max_attempts: 2,
+            ..fast_retry_policy()
+        })
+        .build(access_token_store())
+        .unwrap()
+        .get(GetRecipientsListRequest::default())
+        .await
+        .unwrap_err();
+    assert!(error.is_rate_limited());
+    assert_eq!(Some(Duration::from_secs(7)), error.retry_after());
+    assert_eq!(2, server.requests().len());
+}
+
+#[tokio::test]
+async fn test_rate_limited_with_delay_longer_than_max_delay() {
+    let server = ScriptedServer::start(vec![
+        response(429, &[("Retry-After", "3600")], ""),
+        response(200, &[], r#"{"list": []}"#),
+    ])
+    .await;
+    let error = client(&server)
+        .await
+        .get(GetRecipientsListRequest::default())
+        .await
+        .unwrap_err();
+    assert!(error.is_rate_limited());
+    assert_eq!(Some(Duration::from_secs(3600)), error.retry_after());
+    assert_eq!(1, server.requests().len());
+}
@@ -1,6 +1,15 @@
+use std::convert::{TryFrom, TryInto};
+
 use scoopit_api::{
-    types::Source, CreateSuggestionEngineSourceResponse, EmptyUpdateResponse,
-    GetSuggestionEngineSourcesResponse, GetSuggestionEnginesResponse, GetTopicGroupResponse,
+    types::Notification,
+    types::ResolvedObject,
+    types::Sharer,
+    types::{NewsletterSend, NewsletterSendStatus},
+    types::{Post, PostCounters, Source},
+    CreateSuggestionEngineSourceResponse, EmptyUpdateResponse, GetNotificationsResponse,
+    GetSharersResponse, GetSuggestionEngineContentResponse, GetSuggestionEngineSourcesResponse,
+    GetSuggestionEnginesResponse, GetTopicGroupResponse, GetTopicStatsResponse, PostResponse,
+    ResolveUrlResponse, SendNewsletterResponse,
 };
 
 #[test]
@@ -51,3 +60,101 @@ fn test_get_topic_group() {
     serde_json::from_str::<GetTopicGroupResponse>(include_str!("samples/topic_group.json"))
         .unwrap();
 }
+
+#[test]
+fn test_get_post() {
+    let post: Post = serde_json::from_str::<PostResponse>(include_str!("samples/post.json"))
+        .unwrap()
+        .try_into()
+        .unwrap();
+    assert_eq!(4137629012, post.id);
+    assert_eq!(1, post.comments.unwrap().len());
+
+    assert!(Post::try_from(
+        serde_json::from_str::<PostResponse>(include_str!("samples/update_error.json")).unwrap()
+    )
+    .is_err());
+}
+
+#[test]
+fn test_post_counters() {
+    let counters: PostCounters =
+        serde_json::from_str::<PostResponse>(include_str!("samples/post.json"))
+            .unwrap()
+            .try_into()
+            .unwrap();
+    assert_eq!(
+        PostCounters {
+            thanks_count: 3,
+            reactions_count: 1
+        },
+        counters
+    );
+}
+
+#[test]
+fn test_get_sharers() {
+    let sharers: Vec<Sharer> =
+        serde_json::from_str::<GetSharersResponse>(include_str!("samples/sharers.json"))
+            .unwrap()
+            .try_into()
+            .unwrap();
+    assert_eq!(2, sharers.len());
+    assert_eq!(12345, sharers[0].target().cnx_id);
+}
+
+#[test]
+fn test_get_topic_stats() {
+    serde_json::from_str::<GetTopicStatsResponse>(include_str!("samples/topic_stats.json"))
+        .unwrap();
+}
+
+#[test]
+fn test_get_notifications() {
+    let notifications: Vec<Notification> = serde_json::from_str::<GetNotificationsResponse>(
+        include_str!("samples/notifications.json"),
+    )
+    .unwrap()
+    .try_into()
+    .unwrap();
+    assert_eq!(3, notifications.len());
+    assert!(matches!(notifications[0], Notification::NewFollower { .. }));
+    assert!(matches!(notifications[1], Notification::Thank { .. }));
+    assert!(matches!(notifications[2], Notification::Unknown));
+}
+
+#[test]
+fn test_resolve_url() {
+    let resolved: ResolvedObject = serde_json::from_str::<ResolveUrlResponse>(
+        r#"{"success": true, "type": "post", "id": 4137629012}"#,
+    )
+    .unwrap()
+    .try_into()
+    .unwrap();
+    assert_eq!(ResolvedObject::Post { id: 4137629012 }, resolved);
+
+    assert!(ResolvedObject::try_from(
+        serde_json::from_str::<ResolveUrlResponse>(include_str!("samples/update_error.json"))
+            .unwrap()
+    )
+    .is_err());
+}
+
+#[test]
+fn test_send_newsletter() {
+    let newsletter: NewsletterSend = serde_json::from_str::<SendNewsletterResponse>(
+        r#"{"success": true, "newsletter": {"id": 1234, "status": "queued"}}"#,
+    )
+    .unwrap()
+    .try_into()
+    .unwrap();
+    assert_eq!(NewsletterSendStatus::Queued, newsletter.status);
+}
+
+#[test]
+fn test_get_suggestion_engine_content() {
+    serde_json::from_str::<GetSuggestionEngineContentResponse>(include_str!(
+        "samples/suggestion_engine_content.json"
+    ))
+    .unwrap();
+}